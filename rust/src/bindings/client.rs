@@ -94,12 +94,18 @@ impl Client {
         self.send_command(py, ClientCmd::NewInbox)
     }
 
+    #[pyo3(signature = (max_ack_inflight=None))]
     fn jetstream(
         &self,
         py: Python,
+        max_ack_inflight: Option<usize>,
     ) -> anyhow::Result<Py<PyAny>> {
-        self.send_command(py, ClientCmd::CreateJetStream)
-
+        if max_ack_inflight == Some(0) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "max_ack_inflight must be at least 1"
+            ).into());
+        }
+        self.send_command(py, ClientCmd::CreateJetStream { max_ack_inflight })
     }
 }
 
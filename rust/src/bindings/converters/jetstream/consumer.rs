@@ -1,6 +1,8 @@
 use pyo3::prelude::*;
 use std::time::Duration;
 use crate::bindings::converters::offset_datetime::py_to_offset_datetime;
+use crate::bindings::converters::offset_datetime::offset_datetime_to_py;
+use crate::bindings::converters::jetstream::stream::cluster_info_to_py;
 
 pub fn py_to_consumer_config(
     py_config_dict: &Bound<pyo3::types::PyDict>
@@ -30,10 +32,28 @@ pub fn py_to_consumer_config(
         consumer_config.filter_subjects = value.extract::<Vec<String>>()?;
     }
 
-    // Handle deliver_policy enum
+    // Handle deliver_policy enum, the start policies take their start from another key
     if let Some(value) = py_config_dict.get_item("deliver_policy")? {
         let policy: String = value.extract()?;
-        consumer_config.deliver_policy = str_to_deliver_policy(&policy)?;
+        consumer_config.deliver_policy = match policy.as_str() {
+            "by_start_sequence" => {
+                let Some(value) = py_config_dict.get_item("start_sequence")? else {
+                    anyhow::bail!("Deliver policy by_start_sequence needs start_sequence");
+                };
+                async_nats::jetstream::consumer::DeliverPolicy::ByStartSequence {
+                    start_sequence: value.extract()?
+                }
+            },
+            "by_start_time" => {
+                let Some(value) = py_config_dict.get_item("start_time")? else {
+                    anyhow::bail!("Deliver policy by_start_time needs start_time");
+                };
+                async_nats::jetstream::consumer::DeliverPolicy::ByStartTime {
+                    start_time: py_to_offset_datetime(&value)?
+                }
+            },
+            policy => str_to_deliver_policy(policy)?,
+        };
     }
 
     // Handle ack_policy enum
@@ -132,6 +152,84 @@ pub fn py_to_consumer_config(
     Ok(consumer_config)
 }
 
+pub fn py_to_ordered_consumer_config(
+    py_config_dict: &Bound<pyo3::types::PyDict>
+) -> anyhow::Result<async_nats::jetstream::consumer::pull::OrderedConfig> {
+    // Ordered consumers manage acks, delivery and replicas themselves,
+    // only the fields below are taken over from the pull config.
+    let config = py_to_consumer_config(py_config_dict)?;
+
+    Ok(async_nats::jetstream::consumer::pull::OrderedConfig {
+        name: config.name,
+        description: config.description,
+        filter_subject: config.filter_subject,
+        filter_subjects: config.filter_subjects,
+        replay_policy: config.replay_policy,
+        rate_limit: config.rate_limit,
+        sample_frequency: config.sample_frequency,
+        headers_only: config.headers_only,
+        deliver_policy: config.deliver_policy,
+        max_waiting: config.max_waiting,
+        metadata: config.metadata,
+        max_batch: config.max_batch,
+        max_bytes: config.max_bytes,
+        max_expires: config.max_expires,
+    })
+}
+
+pub fn py_to_push_consumer_config(
+    py_config_dict: &Bound<pyo3::types::PyDict>
+) -> anyhow::Result<async_nats::jetstream::consumer::push::Config> {
+    // The fields shared with pull consumers are handled by the pull converter.
+    let config = py_to_consumer_config(py_config_dict)?;
+
+    let deliver_subject = py_config_dict
+        .get_item("deliver_subject")?
+        .map(|ob| ob.extract())
+        .transpose()?
+        .ok_or_else(|| anyhow::anyhow!("Push consumer must have a deliver_subject"))?;
+
+    let mut consumer_config = async_nats::jetstream::consumer::push::Config {
+        deliver_subject,
+        durable_name: config.durable_name,
+        name: config.name,
+        description: config.description,
+        deliver_policy: config.deliver_policy,
+        ack_policy: config.ack_policy,
+        ack_wait: config.ack_wait,
+        max_deliver: config.max_deliver,
+        filter_subject: config.filter_subject,
+        filter_subjects: config.filter_subjects,
+        replay_policy: config.replay_policy,
+        rate_limit: config.rate_limit,
+        sample_frequency: config.sample_frequency,
+        max_waiting: config.max_waiting,
+        max_ack_pending: config.max_ack_pending,
+        headers_only: config.headers_only,
+        num_replicas: config.num_replicas,
+        memory_storage: config.memory_storage,
+        metadata: config.metadata,
+        backoff: config.backoff,
+        inactive_threshold: config.inactive_threshold,
+        pause_until: config.pause_until,
+        ..Default::default()
+    };
+
+    if let Some(value) = py_config_dict.get_item("deliver_group")? {
+        consumer_config.deliver_group = value.extract::<Option<String>>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("flow_control")? {
+        consumer_config.flow_control = value.is_truthy()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("idle_heartbeat")? {
+        consumer_config.idle_heartbeat = value.extract::<Duration>()?;
+    }
+
+    Ok(consumer_config)
+}
+
 fn str_to_deliver_policy(
     policy_str: &str
 ) -> anyhow::Result<async_nats::jetstream::consumer::DeliverPolicy> {
@@ -175,4 +273,151 @@ fn str_to_priority_policy(
         "none" => Ok(async_nats::jetstream::consumer::PriorityPolicy::None),
         policy => anyhow::bail!("Invalid priority policy: {policy}"),
     }
-}
\ No newline at end of file
+}
+
+pub(crate) fn consumer_info_to_py<'py>(
+    py: Python<'py>,
+    info: &async_nats::jetstream::consumer::Info
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_info = pyo3::types::PyDict::new(py);
+
+    py_info.set_item("stream_name", info.stream_name.as_str())?;
+    py_info.set_item("name", info.name.as_str())?;
+    py_info.set_item("created", offset_datetime_to_py(py, info.created)?)?;
+    py_info.set_item("config", consumer_config_to_py(py, &info.config)?)?;
+    py_info.set_item("delivered", sequence_info_to_py(py, &info.delivered)?)?;
+    py_info.set_item("ack_floor", sequence_info_to_py(py, &info.ack_floor)?)?;
+    py_info.set_item("num_ack_pending", info.num_ack_pending)?;
+    py_info.set_item("num_redelivered", info.num_redelivered)?;
+    py_info.set_item("num_waiting", info.num_waiting)?;
+    py_info.set_item("num_pending", info.num_pending)?;
+    py_info.set_item("push_bound", info.push_bound)?;
+    py_info.set_item("paused", info.paused)?;
+    py_info.set_item("pause_remaining", info.pause_remaining)?;
+
+    if let Some(cluster) = &info.cluster {
+        py_info.set_item("cluster", cluster_info_to_py(py, cluster)?)?;
+    }
+
+    Ok(py_info)
+}
+
+fn sequence_info_to_py<'py>(
+    py: Python<'py>,
+    sequence_info: &async_nats::jetstream::consumer::SequenceInfo
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_sequence_info = pyo3::types::PyDict::new(py);
+
+    py_sequence_info.set_item("consumer_sequence", sequence_info.consumer_sequence)?;
+    py_sequence_info.set_item("stream_sequence", sequence_info.stream_sequence)?;
+
+    if let Some(last_active) = sequence_info.last_active {
+        py_sequence_info.set_item("last_active", offset_datetime_to_py(py, last_active)?)?;
+    }
+
+    Ok(py_sequence_info)
+}
+
+pub(crate) fn consumer_config_to_py<'py>(
+    py: Python<'py>,
+    config: &async_nats::jetstream::consumer::Config
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_config = pyo3::types::PyDict::new(py);
+
+    py_config.set_item("deliver_subject", config.deliver_subject.as_deref())?;
+    py_config.set_item("durable_name", config.durable_name.as_deref())?;
+    py_config.set_item("name", config.name.as_deref())?;
+    py_config.set_item("description", config.description.as_deref())?;
+    py_config.set_item("deliver_group", config.deliver_group.as_deref())?;
+    py_config.set_item("filter_subject", config.filter_subject.as_str())?;
+    py_config.set_item("filter_subjects", &config.filter_subjects)?;
+
+    match config.deliver_policy {
+        async_nats::jetstream::consumer::DeliverPolicy::ByStartSequence { start_sequence } => {
+            py_config.set_item("deliver_policy", "by_start_sequence")?;
+            py_config.set_item("start_sequence", start_sequence)?;
+        },
+        async_nats::jetstream::consumer::DeliverPolicy::ByStartTime { start_time } => {
+            py_config.set_item("deliver_policy", "by_start_time")?;
+            py_config.set_item("start_time", offset_datetime_to_py(py, start_time)?)?;
+        },
+        policy => {
+            py_config.set_item("deliver_policy", deliver_policy_to_str(policy))?;
+        }
+    }
+
+    py_config.set_item("ack_policy", ack_policy_to_str(config.ack_policy))?;
+    py_config.set_item("replay_policy", replay_policy_to_str(config.replay_policy))?;
+    py_config.set_item("priority_policy", priority_policy_to_str(&config.priority_policy))?;
+
+    py_config.set_item("ack_wait", config.ack_wait)?;
+    py_config.set_item("max_expires", config.max_expires)?;
+    py_config.set_item("inactive_threshold", config.inactive_threshold)?;
+    py_config.set_item("idle_heartbeat", config.idle_heartbeat)?;
+
+    py_config.set_item("max_deliver", config.max_deliver)?;
+    py_config.set_item("rate_limit", config.rate_limit)?;
+    py_config.set_item("sample_frequency", config.sample_frequency)?;
+    py_config.set_item("max_waiting", config.max_waiting)?;
+    py_config.set_item("max_ack_pending", config.max_ack_pending)?;
+    py_config.set_item("max_batch", config.max_batch)?;
+    py_config.set_item("max_bytes", config.max_bytes)?;
+    py_config.set_item("num_replicas", config.num_replicas)?;
+
+    py_config.set_item("headers_only", config.headers_only)?;
+    py_config.set_item("flow_control", config.flow_control)?;
+    py_config.set_item("memory_storage", config.memory_storage)?;
+
+    py_config.set_item("metadata", &config.metadata)?;
+    py_config.set_item("backoff", &config.backoff)?;
+    py_config.set_item("priority_groups", &config.priority_groups)?;
+
+    if let Some(pause_until) = config.pause_until {
+        py_config.set_item("pause_until", offset_datetime_to_py(py, pause_until)?)?;
+    }
+
+    Ok(py_config)
+}
+
+fn deliver_policy_to_str(
+    policy: async_nats::jetstream::consumer::DeliverPolicy
+) -> &'static str {
+    match policy {
+        async_nats::jetstream::consumer::DeliverPolicy::All => "all",
+        async_nats::jetstream::consumer::DeliverPolicy::Last => "last",
+        async_nats::jetstream::consumer::DeliverPolicy::New => "new",
+        async_nats::jetstream::consumer::DeliverPolicy::LastPerSubject => "last_per_subject",
+        async_nats::jetstream::consumer::DeliverPolicy::ByStartSequence { .. } => "by_start_sequence",
+        async_nats::jetstream::consumer::DeliverPolicy::ByStartTime { .. } => "by_start_time",
+    }
+}
+
+fn ack_policy_to_str(
+    policy: async_nats::jetstream::consumer::AckPolicy
+) -> &'static str {
+    match policy {
+        async_nats::jetstream::consumer::AckPolicy::Explicit => "explicit",
+        async_nats::jetstream::consumer::AckPolicy::None => "none",
+        async_nats::jetstream::consumer::AckPolicy::All => "all",
+    }
+}
+
+fn replay_policy_to_str(
+    policy: async_nats::jetstream::consumer::ReplayPolicy
+) -> &'static str {
+    match policy {
+        async_nats::jetstream::consumer::ReplayPolicy::Instant => "instant",
+        async_nats::jetstream::consumer::ReplayPolicy::Original => "original",
+    }
+}
+
+fn priority_policy_to_str(
+    policy: &async_nats::jetstream::consumer::PriorityPolicy
+) -> &'static str {
+    match policy {
+        async_nats::jetstream::consumer::PriorityPolicy::Overflow => "overflow",
+        async_nats::jetstream::consumer::PriorityPolicy::PinnedClient => "pinned_client",
+        async_nats::jetstream::consumer::PriorityPolicy::Prioritized => "prioritized",
+        async_nats::jetstream::consumer::PriorityPolicy::None => "none",
+    }
+}
@@ -0,0 +1,131 @@
+use pyo3::prelude::*;
+use std::time::Duration;
+
+use crate::bindings::converters::offset_datetime::offset_datetime_to_py;
+use crate::bindings::converters::jetstream::stream::py_to_placement;
+use crate::bindings::converters::jetstream::stream::py_to_republish;
+use crate::bindings::converters::jetstream::stream::py_to_source;
+
+pub fn py_to_kv_config(
+    py_config_dict: &Bound<'_, pyo3::types::PyDict>
+) -> anyhow::Result<async_nats::jetstream::kv::Config> {
+    let bucket = py_config_dict
+        .get_item("bucket")?
+        .map(|ob| ob.extract())
+        .transpose()?
+        .ok_or_else(|| anyhow::anyhow!("KeyValue bucket must have a name"))?;
+
+    let mut kv_config = async_nats::jetstream::kv::Config {
+        bucket,
+        ..Default::default()
+    };
+
+    if let Some(value) = py_config_dict.get_item("description")? {
+        kv_config.description = value.extract::<String>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("history")? {
+        kv_config.history = value.extract::<i64>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("max_age")? {
+        kv_config.max_age = value.extract::<Duration>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("max_bytes")? {
+        kv_config.max_bytes = value.extract::<i64>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("max_value_size")? {
+        kv_config.max_value_size = value.extract::<i32>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("storage")? {
+        let storage: String = value.extract()?;
+        kv_config.storage = match storage.as_str() {
+            "file" => async_nats::jetstream::stream::StorageType::File,
+            "memory" => async_nats::jetstream::stream::StorageType::Memory,
+            typename => anyhow::bail!("Invalid storage type: {typename}")
+        };
+    }
+
+    if let Some(value) = py_config_dict.get_item("num_replicas")? {
+        kv_config.num_replicas = value.extract::<usize>()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("compression")? {
+        kv_config.compression = value.is_truthy()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("mirror_direct")? {
+        kv_config.mirror_direct = value.is_truthy()?;
+    }
+
+    if let Some(value) = py_config_dict.get_item("republish")? {
+        kv_config.republish = Some(py_to_republish(&value)?);
+    }
+
+    if let Some(value) = py_config_dict.get_item("mirror")? {
+        kv_config.mirror = Some(py_to_source(&value)?);
+    }
+
+    if let Some(value) = py_config_dict.get_item("sources")? {
+        let sources_list = value.extract::<Vec<Bound<pyo3::types::PyDict>>>()?;
+        kv_config.sources = Some(
+            sources_list.iter()
+                .map(|s| py_to_source(s))
+                .collect::<Result<Vec<_>, _>>()?
+        );
+    }
+
+    if let Some(value) = py_config_dict.get_item("placement")? {
+        kv_config.placement = Some(py_to_placement(&value)?);
+    }
+
+    if let Some(value) = py_config_dict.get_item("limit_markers")? {
+        kv_config.limit_markers = Some(value.extract::<Duration>()?);
+    }
+
+    Ok(kv_config)
+}
+
+pub(crate) fn kv_entry_to_py<'py>(
+    py: Python<'py>,
+    entry: &async_nats::jetstream::kv::Entry
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_entry = pyo3::types::PyDict::new(py);
+
+    py_entry.set_item("bucket", entry.bucket.as_str())?;
+    py_entry.set_item("key", entry.key.as_str())?;
+    py_entry.set_item("value", entry.value.as_ref())?;
+    py_entry.set_item("revision", entry.revision)?;
+    py_entry.set_item("delta", entry.delta)?;
+    py_entry.set_item("created", offset_datetime_to_py(py, entry.created)?)?;
+    py_entry.set_item("operation", match entry.operation {
+        async_nats::jetstream::kv::Operation::Put => "put",
+        async_nats::jetstream::kv::Operation::Delete => "delete",
+        async_nats::jetstream::kv::Operation::Purge => "purge",
+    })?;
+
+    Ok(py_entry)
+}
+
+pub(crate) fn kv_status_to_py<'py>(
+    py: Python<'py>,
+    status: &async_nats::jetstream::kv::bucket::Status
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_status = pyo3::types::PyDict::new(py);
+
+    py_status.set_item("bucket", status.bucket())?;
+    py_status.set_item("values", status.values())?;
+    py_status.set_item("bytes", status.info.state.bytes)?;
+    py_status.set_item("history", status.history())?;
+    py_status.set_item("max_age", status.max_age())?;
+    py_status.set_item("stream_name", status.info.config.name.as_str())?;
+    py_status.set_item(
+        "stream_info",
+        crate::bindings::converters::jetstream::stream::stream_info_to_py(py, &status.info, None)?
+    )?;
+
+    Ok(py_status)
+}
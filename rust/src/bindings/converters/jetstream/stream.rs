@@ -2,6 +2,7 @@ use pyo3::prelude::*;
 use std::time::Duration;
 
 use crate::bindings::converters::offset_datetime::py_to_offset_datetime;
+use crate::bindings::converters::offset_datetime::offset_datetime_to_py;
 
 pub fn py_to_stream_config(
     py_config_dict: &Bound<'_, pyo3::types::PyDict>
@@ -218,7 +219,7 @@ fn py_to_subject_transform(
     })
 }
 
-fn py_to_source(
+pub(crate) fn py_to_source(
     py_dict: &Bound<pyo3::types::PyAny>
 ) -> anyhow::Result<async_nats::jetstream::stream::Source> {
 
@@ -300,7 +301,7 @@ fn py_to_consumer_limits(
     })
 }
 
-fn py_to_republish(
+pub(crate) fn py_to_republish(
     py_dict: &Bound<pyo3::PyAny>
 ) -> anyhow::Result<async_nats::jetstream::stream::Republish> {
     let source: String = py_dict.get_item("source")?.extract()?;
@@ -314,7 +315,7 @@ fn py_to_republish(
     })
 }
 
-fn py_to_placement(
+pub(crate) fn py_to_placement(
     py_dict: &Bound<pyo3::PyAny>
 ) -> anyhow::Result<async_nats::jetstream::stream::Placement> {
     let cluster: Option<String> = py_dict.get_item("cluster")?.extract()?;
@@ -336,3 +337,275 @@ fn str_to_persistence_mode(
     }
 }
 
+pub(crate) fn cluster_info_to_py<'py>(
+    py: Python<'py>,
+    cluster: &async_nats::jetstream::stream::ClusterInfo
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_cluster = pyo3::types::PyDict::new(py);
+
+    py_cluster.set_item("name", cluster.name.as_deref())?;
+    py_cluster.set_item("raft_group", cluster.raft_group.as_deref())?;
+    py_cluster.set_item("leader", cluster.leader.as_deref())?;
+    py_cluster.set_item(
+        "leader_since",
+        cluster.leader_since.map(|dt| offset_datetime_to_py(py, dt)).transpose()?
+    )?;
+    py_cluster.set_item("system_account", cluster.system_account)?;
+    py_cluster.set_item("traffic_account", cluster.traffic_account.as_deref())?;
+
+    let replicas = pyo3::types::PyList::empty(py);
+    for peer in &cluster.replicas {
+        let py_peer = pyo3::types::PyDict::new(py);
+        py_peer.set_item("name", peer.name.as_str())?;
+        py_peer.set_item("current", peer.current)?;
+        py_peer.set_item("active", peer.active)?;
+        py_peer.set_item("offline", peer.offline)?;
+        py_peer.set_item("lag", peer.lag)?;
+        replicas.append(py_peer)?;
+    }
+    py_cluster.set_item("replicas", replicas)?;
+
+    Ok(py_cluster)
+}
+
+pub(crate) fn stream_info_to_py<'py>(
+    py: Python<'py>,
+    info: &async_nats::jetstream::stream::Info,
+    subjects: Option<&std::collections::HashMap<String, usize>>
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_info = pyo3::types::PyDict::new(py);
+
+    py_info.set_item("config", stream_config_to_py(py, &info.config)?)?;
+    py_info.set_item("created", offset_datetime_to_py(py, info.created)?)?;
+    py_info.set_item("state", stream_state_to_py(py, &info.state, subjects)?)?;
+
+    if let Some(cluster) = &info.cluster {
+        py_info.set_item("cluster", cluster_info_to_py(py, cluster)?)?;
+    }
+
+    if let Some(mirror) = &info.mirror {
+        py_info.set_item("mirror", source_info_to_py(py, mirror)?)?;
+    }
+
+    let sources = pyo3::types::PyList::empty(py);
+    for source in &info.sources {
+        sources.append(source_info_to_py(py, source)?)?;
+    }
+    py_info.set_item("sources", sources)?;
+
+    Ok(py_info)
+}
+
+fn stream_state_to_py<'py>(
+    py: Python<'py>,
+    state: &async_nats::jetstream::stream::State,
+    subjects: Option<&std::collections::HashMap<String, usize>>
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_state = pyo3::types::PyDict::new(py);
+
+    py_state.set_item("messages", state.messages)?;
+    py_state.set_item("bytes", state.bytes)?;
+    py_state.set_item("first_sequence", state.first_sequence)?;
+    py_state.set_item("first_timestamp", offset_datetime_to_py(py, state.first_timestamp)?)?;
+    py_state.set_item("last_sequence", state.last_sequence)?;
+    py_state.set_item("last_timestamp", offset_datetime_to_py(py, state.last_timestamp)?)?;
+    py_state.set_item("consumer_count", state.consumer_count)?;
+    py_state.set_item("subjects_count", state.subjects_count)?;
+    py_state.set_item("deleted_count", state.deleted_count)?;
+    py_state.set_item("deleted", &state.deleted)?;
+
+    if let Some(subjects) = subjects {
+        py_state.set_item("subjects", subjects)?;
+    }
+
+    Ok(py_state)
+}
+
+fn source_info_to_py<'py>(
+    py: Python<'py>,
+    source: &async_nats::jetstream::stream::SourceInfo
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_source = pyo3::types::PyDict::new(py);
+
+    py_source.set_item("name", source.name.as_str())?;
+    py_source.set_item("lag", source.lag)?;
+    py_source.set_item("active", source.active)?;
+    py_source.set_item("filter_subject", source.filter_subject.as_deref())?;
+    py_source.set_item("subject_transform_dest", source.subject_transform_dest.as_deref())?;
+
+    let subject_transforms = pyo3::types::PyList::empty(py);
+    for transform in &source.subject_transforms {
+        subject_transforms.append(subject_transform_to_py(py, transform)?)?;
+    }
+    py_source.set_item("subject_transforms", subject_transforms)?;
+
+    Ok(py_source)
+}
+
+pub(crate) fn stream_config_to_py<'py>(
+    py: Python<'py>,
+    config: &async_nats::jetstream::stream::Config
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_config = pyo3::types::PyDict::new(py);
+
+    py_config.set_item("name", config.name.as_str())?;
+    py_config.set_item("no_ack", config.no_ack)?;
+    py_config.set_item("allow_direct", config.allow_direct)?;
+    py_config.set_item("subjects", &config.subjects)?;
+
+    py_config.set_item("retention", match config.retention {
+        async_nats::jetstream::stream::RetentionPolicy::Limits => "limits",
+        async_nats::jetstream::stream::RetentionPolicy::Interest => "interest",
+        async_nats::jetstream::stream::RetentionPolicy::WorkQueue => "workqueue",
+    })?;
+
+    py_config.set_item("storage", match config.storage {
+        async_nats::jetstream::stream::StorageType::File => "file",
+        async_nats::jetstream::stream::StorageType::Memory => "memory",
+    })?;
+
+    py_config.set_item("discard", match config.discard {
+        async_nats::jetstream::stream::DiscardPolicy::New => "new",
+        async_nats::jetstream::stream::DiscardPolicy::Old => "old",
+    })?;
+
+    py_config.set_item("discard_new_per_subject", config.discard_new_per_subject)?;
+
+    py_config.set_item("compression", match config.compression {
+        Some(async_nats::jetstream::stream::Compression::S2) => "s2",
+        Some(async_nats::jetstream::stream::Compression::None) | None => "none",
+    })?;
+
+    py_config.set_item("num_replicas", config.num_replicas)?;
+    py_config.set_item("max_bytes", config.max_bytes)?;
+    py_config.set_item("max_messages", config.max_messages)?;
+    py_config.set_item("max_messages_per_subject", config.max_messages_per_subject)?;
+    py_config.set_item("max_age", config.max_age)?;
+    py_config.set_item("max_message_size", config.max_message_size)?;
+    py_config.set_item("max_consumers", config.max_consumers)?;
+    py_config.set_item("duplicate_window", config.duplicate_window)?;
+    py_config.set_item("sealed", config.sealed)?;
+    py_config.set_item("mirror_direct", config.mirror_direct)?;
+    py_config.set_item("allow_rollup", config.allow_rollup)?;
+    py_config.set_item("deny_delete", config.deny_delete)?;
+    py_config.set_item("deny_purge", config.deny_purge)?;
+    py_config.set_item("allow_message_ttl", config.allow_message_ttl)?;
+    py_config.set_item("allow_atomic_publish", config.allow_atomic_publish)?;
+    py_config.set_item("allow_message_schedules", config.allow_message_schedules)?;
+    py_config.set_item("allow_message_counter", config.allow_message_counter)?;
+    py_config.set_item("template_owner", config.template_owner.as_str())?;
+    py_config.set_item("metadata", &config.metadata)?;
+
+    if let Some(description) = &config.description {
+        py_config.set_item("description", description.as_str())?;
+    }
+
+    if let Some(first_sequence) = config.first_sequence {
+        py_config.set_item("first_sequence", first_sequence)?;
+    }
+
+    if let Some(republish) = &config.republish {
+        let py_republish = pyo3::types::PyDict::new(py);
+        py_republish.set_item("source", republish.source.as_str())?;
+        py_republish.set_item("destination", republish.destination.as_str())?;
+        py_republish.set_item("headers_only", republish.headers_only)?;
+        py_config.set_item("republish", py_republish)?;
+    }
+
+    if let Some(mirror) = &config.mirror {
+        py_config.set_item("mirror", source_to_py(py, mirror)?)?;
+    }
+
+    if let Some(sources) = &config.sources {
+        let py_sources = pyo3::types::PyList::empty(py);
+        for source in sources {
+            py_sources.append(source_to_py(py, source)?)?;
+        }
+        py_config.set_item("sources", py_sources)?;
+    }
+
+    if let Some(subject_transform) = &config.subject_transform {
+        py_config.set_item("subject_transform", subject_transform_to_py(py, subject_transform)?)?;
+    }
+
+    if let Some(consumer_limits) = &config.consumer_limits {
+        let py_consumer_limits = pyo3::types::PyDict::new(py);
+        py_consumer_limits.set_item("inactive_threshold", consumer_limits.inactive_threshold)?;
+        py_consumer_limits.set_item("max_ack_pending", consumer_limits.max_ack_pending)?;
+        py_config.set_item("consumer_limits", py_consumer_limits)?;
+    }
+
+    if let Some(placement) = &config.placement {
+        let py_placement = pyo3::types::PyDict::new(py);
+        py_placement.set_item("cluster", placement.cluster.as_deref())?;
+        py_placement.set_item("tags", &placement.tags)?;
+        py_config.set_item("placement", py_placement)?;
+    }
+
+    if let Some(persist_mode) = config.persist_mode {
+        py_config.set_item("persist_mode", match persist_mode {
+            async_nats::jetstream::stream::PersistenceMode::Default => "default",
+            async_nats::jetstream::stream::PersistenceMode::Async => "async",
+        })?;
+    }
+
+    if let Some(pause_until) = config.pause_until {
+        py_config.set_item("pause_until", offset_datetime_to_py(py, pause_until)?)?;
+    }
+
+    if let Some(subject_delete_marker_ttl) = config.subject_delete_marker_ttl {
+        py_config.set_item("subject_delete_marker_ttl", subject_delete_marker_ttl)?;
+    }
+
+    Ok(py_config)
+}
+
+fn subject_transform_to_py<'py>(
+    py: Python<'py>,
+    subject_transform: &async_nats::jetstream::stream::SubjectTransform
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_subject_transform = pyo3::types::PyDict::new(py);
+    py_subject_transform.set_item("source", subject_transform.source.as_str())?;
+    py_subject_transform.set_item("destination", subject_transform.destination.as_str())?;
+    Ok(py_subject_transform)
+}
+
+fn source_to_py<'py>(
+    py: Python<'py>,
+    source: &async_nats::jetstream::stream::Source
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDict>> {
+    let py_source = pyo3::types::PyDict::new(py);
+
+    py_source.set_item("name", source.name.as_str())?;
+
+    if let Some(start_sequence) = source.start_sequence {
+        py_source.set_item("start_sequence", start_sequence)?;
+    }
+
+    if let Some(start_time) = source.start_time {
+        py_source.set_item("start_time", offset_datetime_to_py(py, start_time)?)?;
+    }
+
+    if let Some(filter_subject) = &source.filter_subject {
+        py_source.set_item("filter_subject", filter_subject.as_str())?;
+    }
+
+    if let Some(external) = &source.external {
+        let py_external = pyo3::types::PyDict::new(py);
+        py_external.set_item("api_prefix", external.api_prefix.as_str())?;
+        py_external.set_item("delivery_prefix", external.delivery_prefix.as_deref())?;
+        py_source.set_item("external", py_external)?;
+    }
+
+    if let Some(domain) = &source.domain {
+        py_source.set_item("domain", domain.as_str())?;
+    }
+
+    let subject_transforms = pyo3::types::PyList::empty(py);
+    for transform in &source.subject_transforms {
+        subject_transforms.append(subject_transform_to_py(py, transform)?)?;
+    }
+    py_source.set_item("subject_transforms", subject_transforms)?;
+
+    Ok(py_source)
+}
@@ -1,6 +1,7 @@
 use pyo3::prelude::*;
 
 use crate::bindings::converters::headermap::HeaderMap;
+use crate::bindings::converters::offset_datetime::offset_datetime_to_py;
 
 pub(crate) struct Message {
     inner: async_nats::Message
@@ -46,3 +47,34 @@ impl<'py> IntoPyObject<'py> for Message {
         Ok(result.into_any())
     }
 }
+
+pub(crate) struct StreamMessage {
+    inner: async_nats::jetstream::message::StreamMessage
+}
+
+impl StreamMessage {
+    pub(crate) fn new(msg: async_nats::jetstream::message::StreamMessage) -> Self {
+        StreamMessage { inner: msg }
+    }
+}
+
+impl<'py> IntoPyObject<'py> for StreamMessage {
+    type Target = PyAny;
+    type Output = Bound<'py, Self::Target>;
+    type Error = anyhow::Error;
+
+    fn into_pyobject(
+        self,
+        py: Python<'py>,
+    ) -> Result<Self::Output, Self::Error> {
+        let result = pyo3::types::PyDict::new(py);
+
+        result.set_item("subject", self.inner.subject.as_str())?;
+        result.set_item("sequence", self.inner.sequence)?;
+        result.set_item("payload", self.inner.payload.as_ref())?;
+        result.set_item("headers", HeaderMap::new(self.inner.headers))?;
+        result.set_item("time", offset_datetime_to_py(py, self.inner.time)?)?;
+
+        Ok(result.into_any())
+    }
+}
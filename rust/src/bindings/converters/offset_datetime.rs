@@ -9,6 +9,15 @@ pub(crate) fn py_to_offset_datetime(
         Err(_) => anyhow::bail!("Expected datetime")
     };
 
+    // Aware datetimes are converted to UTC, naive ones are taken as UTC.
+    let dt = if dt.getattr("tzinfo")?.is_none() {
+        dt.clone()
+    } else {
+        let utc = pyo3::types::PyTzInfo::utc(py_obj.py())?;
+        dt.call_method1("astimezone", (utc,))?.cast_into::<pyo3::types::PyDateTime>()
+            .map_err(PyErr::from)?
+    };
+
     let year: i32 = dt.getattr("year")?.extract()?;
     let month: u8 = dt.getattr("month")?.extract()?;
     let day: u8 = dt.getattr("day")?.extract()?;
@@ -16,12 +25,33 @@ pub(crate) fn py_to_offset_datetime(
     let hour: u8 = dt.getattr("hour")?.extract()?;
     let minute: u8 = dt.getattr("minute")?.extract()?;
     let second: u8 = dt.getattr("second")?.extract()?;
+    let microsecond: u32 = dt.getattr("microsecond")?.extract()?;
 
     let month= time::Month::try_from(month)?;
 
     let naive_dt = time::PrimitiveDateTime::new(
         time::Date::from_calendar_date(year, month, day)?,
-        time::Time::from_hms(hour, minute, second)?
+        time::Time::from_hms_micro(hour, minute, second, microsecond)?
     );
     Ok(naive_dt.assume_utc())
 }
+
+pub(crate) fn offset_datetime_to_py<'py>(
+    py: Python<'py>,
+    dt: time::OffsetDateTime
+) -> anyhow::Result<Bound<'py, pyo3::types::PyDateTime>> {
+    let dt = dt.to_offset(time::UtcOffset::UTC);
+    let utc = pyo3::types::PyTzInfo::utc(py)?;
+
+    Ok(pyo3::types::PyDateTime::new(
+        py,
+        dt.year(),
+        dt.month().into(),
+        dt.day(),
+        dt.hour(),
+        dt.minute(),
+        dt.second(),
+        dt.microsecond(),
+        Some(&utc)
+    )?)
+}
@@ -0,0 +1,155 @@
+use pyo3::prelude::*;
+
+use async_nats::jetstream::consumer::pull;
+use async_nats::jetstream::consumer::push;
+use async_nats::jetstream::context::CreateStreamError;
+use async_nats::jetstream::context::CreateStreamErrorKind;
+use async_nats::jetstream::context::GetStreamError;
+use async_nats::jetstream::context::GetStreamErrorKind;
+use async_nats::jetstream::context::PublishError;
+use async_nats::jetstream::context::PublishErrorKind;
+use async_nats::jetstream::ErrorCode;
+use async_nats::jetstream::kv;
+
+
+pyo3::create_exception!(no_asyncio_nats, MissingHeartbeatError, pyo3::exceptions::PyException);
+pyo3::create_exception!(no_asyncio_nats, ConsumerDeletedError, pyo3::exceptions::PyException);
+pyo3::create_exception!(no_asyncio_nats, StreamAlreadyExistsError, pyo3::exceptions::PyException);
+pyo3::create_exception!(no_asyncio_nats, StreamNotFoundError, pyo3::exceptions::PyException);
+pyo3::create_exception!(no_asyncio_nats, StreamConfigError, pyo3::exceptions::PyException);
+pyo3::create_exception!(no_asyncio_nats, WrongLastSequenceError, pyo3::exceptions::PyException);
+pyo3::create_exception!(no_asyncio_nats, KeyExistsError, pyo3::exceptions::PyException);
+
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("MissingHeartbeatError", py.get_type::<MissingHeartbeatError>())?;
+    m.add("ConsumerDeletedError", py.get_type::<ConsumerDeletedError>())?;
+    m.add("StreamAlreadyExistsError", py.get_type::<StreamAlreadyExistsError>())?;
+    m.add("StreamNotFoundError", py.get_type::<StreamNotFoundError>())?;
+    m.add("StreamConfigError", py.get_type::<StreamConfigError>())?;
+    m.add("WrongLastSequenceError", py.get_type::<WrongLastSequenceError>())?;
+    m.add("KeyExistsError", py.get_type::<KeyExistsError>())?;
+    Ok(())
+}
+
+/// Receivers raise TimeoutError on timeout, None is left for their end.
+pub(crate) fn map_timeout_error(err: anyhow::Error) -> anyhow::Error {
+    if err.downcast_ref::<tokio::time::error::Elapsed>().is_some() {
+        return pyo3::exceptions::PyTimeoutError::new_err("Nothing received within the timeout").into();
+    }
+    err
+}
+
+pub(crate) fn map_messages_error(err: anyhow::Error) -> anyhow::Error {
+    let err = map_timeout_error(err);
+
+    if let Some(messages_error) = err.downcast_ref::<pull::MessagesError>() {
+        return match messages_error.kind() {
+            pull::MessagesErrorKind::MissingHeartbeat => {
+                MissingHeartbeatError::new_err(messages_error.to_string()).into()
+            },
+            pull::MessagesErrorKind::ConsumerDeleted => {
+                ConsumerDeletedError::new_err(messages_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    if let Some(ordered_error) = err.downcast_ref::<pull::OrderedError>() {
+        return match ordered_error.kind() {
+            pull::OrderedErrorKind::MissingHeartbeat => {
+                MissingHeartbeatError::new_err(ordered_error.to_string()).into()
+            },
+            pull::OrderedErrorKind::ConsumerDeleted => {
+                ConsumerDeletedError::new_err(ordered_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    if let Some(messages_error) = err.downcast_ref::<push::MessagesError>() {
+        return match messages_error.kind() {
+            push::MessagesErrorKind::MissingHeartbeat => {
+                MissingHeartbeatError::new_err(messages_error.to_string()).into()
+            },
+            push::MessagesErrorKind::ConsumerDeleted => {
+                ConsumerDeletedError::new_err(messages_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    err
+}
+
+pub(crate) fn map_stream_error(err: anyhow::Error) -> anyhow::Error {
+    if let Some(create_error) = err.downcast_ref::<CreateStreamError>() {
+        return match create_error.kind() {
+            CreateStreamErrorKind::NotFound => {
+                StreamNotFoundError::new_err(create_error.to_string()).into()
+            },
+            CreateStreamErrorKind::JetStream(error) => match error.error_code() {
+                ErrorCode::STREAM_NAME_EXIST => {
+                    StreamAlreadyExistsError::new_err(create_error.to_string()).into()
+                },
+                ErrorCode::STREAM_NOT_FOUND => {
+                    StreamNotFoundError::new_err(create_error.to_string()).into()
+                },
+                ErrorCode::STREAM_INVALID_CONFIG | ErrorCode::STREAM_UPDATE => {
+                    StreamConfigError::new_err(create_error.to_string()).into()
+                },
+                _ => err
+            },
+            _ => err
+        };
+    }
+
+    if let Some(get_error) = err.downcast_ref::<GetStreamError>() {
+        return match get_error.kind() {
+            GetStreamErrorKind::JetStream(error)
+                if error.error_code() == ErrorCode::STREAM_NOT_FOUND => {
+                StreamNotFoundError::new_err(get_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    err
+}
+
+pub(crate) fn map_publish_error(err: anyhow::Error) -> anyhow::Error {
+    if let Some(publish_error) = err.downcast_ref::<PublishError>() {
+        return match publish_error.kind() {
+            PublishErrorKind::WrongLastSequence => {
+                WrongLastSequenceError::new_err(publish_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    err
+}
+
+pub(crate) fn map_kv_error(err: anyhow::Error) -> anyhow::Error {
+    if let Some(create_error) = err.downcast_ref::<kv::CreateError>() {
+        return match create_error.kind() {
+            kv::CreateErrorKind::AlreadyExists => {
+                KeyExistsError::new_err(create_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    // `kv::DeleteError` and `kv::PurgeError` are aliases of `kv::UpdateError`,
+    // so a wrong revision on `delete` and `purge` is mapped here as well.
+    if let Some(update_error) = err.downcast_ref::<kv::UpdateError>() {
+        return match update_error.kind() {
+            kv::UpdateErrorKind::WrongLastRevision => {
+                WrongLastSequenceError::new_err(update_error.to_string()).into()
+            },
+            _ => err
+        };
+    }
+
+    err
+}
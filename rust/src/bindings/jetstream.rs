@@ -3,10 +3,15 @@ use std::time::Duration;
 
 use crate::bindings::converters;
 use crate::bindings::converters::headermap::HeaderMap;
+use crate::bindings::exceptions::map_stream_error;
 
 use crate::cmds::jetstream::JetStreamCmd;
 use crate::cmds::jetstream::JetStreamCmdResponse;
 
+pub(crate) mod kv;
+pub(crate) mod list;
+pub(crate) mod lock;
+pub(crate) mod message;
 pub(crate) mod publish_ack;
 pub(crate) mod stream;
 
@@ -23,20 +28,144 @@ impl JetStream {
         self.send_command(py, command)
     }
 
-    #[pyo3(signature = (subject, data, headers=None))]
+    #[pyo3(signature = (
+        subject,
+        data,
+        headers=None,
+        msg_id=None,
+        expected_stream=None,
+        expected_last_sequence=None,
+        expected_last_subject_sequence=None,
+        expected_last_msg_id=None,
+        ttl=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn publish(
         &self,
         py: Python,
         subject: String,
         data: &[u8],
-        headers: Option<HeaderMap>
+        headers: Option<HeaderMap>,
+        msg_id: Option<String>,
+        expected_stream: Option<String>,
+        expected_last_sequence: Option<u64>,
+        expected_last_subject_sequence: Option<u64>,
+        expected_last_msg_id: Option<String>,
+        ttl: Option<&Bound<PyAny>>
     ) -> anyhow::Result<Py<PyAny>> {
         let payload_bytes = bytes::Bytes::copy_from_slice(data);
-        let command = JetStreamCmd::Publish {
-            subject,
-            payload: payload_bytes,
-            headers: headers.map(Into::into)
+        let mut publish = async_nats::jetstream::message::PublishMessage::build()
+            .payload(payload_bytes);
+
+        // Set the user headers first, the options below are added to them.
+        if let Some(headers) = headers {
+            publish = publish.headers(headers.into());
+        }
+        if let Some(msg_id) = msg_id {
+            publish = publish.message_id(msg_id);
+        }
+        if let Some(expected_stream) = expected_stream {
+            publish = publish.expected_stream(expected_stream);
+        }
+        if let Some(expected_last_sequence) = expected_last_sequence {
+            publish = publish.expected_last_sequence(expected_last_sequence);
+        }
+        if let Some(expected_last_subject_sequence) = expected_last_subject_sequence {
+            publish = publish.expected_last_subject_sequence(expected_last_subject_sequence);
+        }
+        if let Some(expected_last_msg_id) = expected_last_msg_id {
+            publish = publish.expected_last_message_id(expected_last_msg_id);
+        }
+        if let Some(ttl) = ttl {
+            publish = with_message_ttl(publish, ttl)?;
+        }
+
+        let command = JetStreamCmd::Publish { subject, publish };
+        self.send_command(py, command)
+    }
+
+    #[pyo3(signature = (subject, data, target, at=None, every=None, headers=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn schedule(
+        &self,
+        py: Python,
+        subject: String,
+        data: &[u8],
+        target: String,
+        at: Option<&Bound<PyAny>>,
+        every: Option<&Bound<PyAny>>,
+        headers: Option<HeaderMap>
+    ) -> anyhow::Result<Py<PyAny>> {
+        let pattern = match (at, every) {
+            (Some(at), None) => {
+                let at = converters::offset_datetime::py_to_offset_datetime(at)?
+                    .format(&time::format_description::well_known::Rfc3339)?;
+                format!("@at {at}")
+            },
+            (None, Some(every)) => py_to_schedule_interval(every)?,
+            _ => anyhow::bail!("Exactly one of at and every must be given"),
         };
+
+        let payload_bytes = bytes::Bytes::copy_from_slice(data);
+        let mut publish = async_nats::jetstream::message::PublishMessage::build()
+            .payload(payload_bytes);
+        if let Some(headers) = headers {
+            publish = publish.headers(headers.into());
+        }
+        let publish = publish
+            .header(crate::cmds::jetstream::NATS_SCHEDULE, pattern)
+            .header(crate::cmds::jetstream::NATS_SCHEDULE_TARGET, target);
+
+        let command = JetStreamCmd::Publish { subject, publish };
+        self.send_command(py, command)
+    }
+
+    fn publish_batch(
+        &self,
+        py: Python,
+        messages: Vec<(String, Vec<u8>, Option<HeaderMap>)>
+    ) -> anyhow::Result<Py<PyAny>> {
+        // The server would only reject the batch with its last message.
+        if messages.is_empty() || messages.len() > crate::cmds::jetstream::MAX_BATCH_SIZE {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "A batch needs 1 to {} messages", crate::cmds::jetstream::MAX_BATCH_SIZE
+            )).into());
+        }
+
+        let messages = messages
+            .into_iter()
+            .map(|(subject, data, headers)| (subject, data.into(), headers.map(Into::into)))
+            .collect();
+        let command = JetStreamCmd::PublishBatch { messages };
+        self.send_command(py, command)
+    }
+
+    fn wait_all(
+        &self,
+        py: Python,
+        futures: Vec<Py<publish_ack::PublishAckFuture>>
+    ) -> anyhow::Result<Py<PyAny>> {
+        publish_ack::wait_all(py, &self.task_spawner, futures)
+    }
+
+    fn counter_add(
+        &self,
+        py: Python,
+        subject: String,
+        delta: &Bound<'_, pyo3::types::PyInt>
+    ) -> anyhow::Result<Py<PyAny>> {
+        // bool is a subclass of int, other subclasses like IntEnum may
+        // change str(), so the value goes through int() first.
+        if delta.is_instance_of::<pyo3::types::PyBool>() {
+            return Err(pyo3::exceptions::PyTypeError::new_err("delta must be an int, not bool").into());
+        }
+        let delta = py.get_type::<pyo3::types::PyInt>().call1((delta,))?.str()?.to_string();
+        let command = JetStreamCmd::CounterAdd { subject, delta };
+        self.send_command(py, command)
+    }
+
+    fn counter_get(&self, py: Python, subject: String) -> anyhow::Result<Py<PyAny>> {
+        let command = JetStreamCmd::CounterGet { subject };
         self.send_command(py, command)
     }
 
@@ -50,10 +179,69 @@ impl JetStream {
         self.send_command(py, command)
     }
 
+    fn create_stream(
+        &self,
+        py: Python,
+        stream_config: &Bound<'_, pyo3::types::PyDict>,
+    ) -> anyhow::Result<Py<PyAny>> {
+        let config = converters::jetstream::stream::py_to_stream_config(stream_config)?;
+        let command = JetStreamCmd::CreateStream { config };
+        self.send_command(py, command).map_err(map_stream_error)
+    }
+
+    fn update_stream(
+        &self,
+        py: Python,
+        stream_config: &Bound<'_, pyo3::types::PyDict>,
+    ) -> anyhow::Result<Py<PyAny>> {
+        let config = converters::jetstream::stream::py_to_stream_config(stream_config)?;
+        let command = JetStreamCmd::UpdateStream { config };
+        self.send_command(py, command).map_err(map_stream_error)
+    }
+
+    fn get_stream(&self, py: Python, stream: String) -> anyhow::Result<Py<PyAny>> {
+        let command = JetStreamCmd::GetStream { stream };
+        self.send_command(py, command).map_err(map_stream_error)
+    }
+
+    fn create_key_value(
+        &self,
+        py: Python,
+        kv_config: &Bound<'_, pyo3::types::PyDict>,
+    ) -> anyhow::Result<Py<PyAny>> {
+        let config = converters::jetstream::kv::py_to_kv_config(kv_config)?;
+        let command = JetStreamCmd::CreateKeyValue { config };
+        self.send_command(py, command)
+    }
+
+    fn key_value(&self, py: Python, bucket: String) -> anyhow::Result<Py<PyAny>> {
+        let command = JetStreamCmd::KeyValue { bucket };
+        self.send_command(py, command)
+    }
+
+    fn delete_key_value(&self, py: Python, bucket: String) -> anyhow::Result<Py<PyAny>> {
+        let command = JetStreamCmd::DeleteKeyValue { bucket };
+        self.send_command(py, command)
+    }
+
     fn delete_stream(&self, py: Python, stream: String) -> anyhow::Result<Py<PyAny>> {
         let command = JetStreamCmd::DeleteStream { stream };
         self.send_command(py, command)
     }
+
+    #[pyo3(signature = (subject_filter=None))]
+    fn stream_names(
+        &self,
+        py: Python,
+        subject_filter: Option<String>
+    ) -> anyhow::Result<Py<PyAny>> {
+        let command = JetStreamCmd::StreamNames { subject_filter };
+        self.send_command(py, command)
+    }
+
+    fn streams(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamCmd::Streams)
+    }
 }
 
 impl JetStream {
@@ -76,29 +264,112 @@ impl JetStream {
             JetStreamCmdResponse::DeleteStatus { success } => {
                 Ok(success.into_pyobject(py)?.to_owned().into())
             },
-            JetStreamCmdResponse::PublishAck { future } => {
-                let future = publish_ack::PublishAckFuture{
-                    future: Some(future),
-                    task_spawner: self.task_spawner.make_clone(py)
+            JetStreamCmdResponse::CounterValue { value } => {
+                let Some(value) = value else {
+                    return Ok(py.None());
                 };
+                // Parsed by Python so values beyond 64 bits keep their precision.
+                let py_value = py.get_type::<pyo3::types::PyInt>().call1((value,))?;
+                Ok(py_value.unbind())
+            },
+            JetStreamCmdResponse::PublishAck { future } => {
+                let future = publish_ack::PublishAckFuture::spawn(py, &self.task_spawner, future);
                 Ok(Py::new(py, future)?.into())
             },
-            JetStreamCmdResponse::Stream{stream} => {
+            JetStreamCmdResponse::Stream{stream, context} => {
                 let (task_caller, queue) =
                     crate::task::TaskCaller::from_other(py, &self.task_caller);
 
+                let stream_context = context.clone();
                 self.task_spawner.spawn(async move {
-                    crate::cmds::jetstream::stream_loop(stream, queue)
+                    crate::cmds::jetstream::stream_loop(stream, stream_context, queue)
                         .await
                 });
 
                 let stream = stream::JetStreamStream{
                     task_caller: task_caller,
-                    task_spawner: self.task_spawner.make_clone(py)
+                    task_spawner: self.task_spawner.make_clone(py),
+                    context
                 };
 
                 Ok(Py::new(py, stream)?.into())
+            },
+            JetStreamCmdResponse::KeyValue{store, context} => {
+                let (task_caller, queue) =
+                    crate::task::TaskCaller::from_other(py, &self.task_caller);
+
+                let loop_store = store.clone();
+                let idle_timeout = context.client().timeout().unwrap_or(Duration::from_secs(10));
+                self.task_spawner.spawn(async move {
+                    crate::cmds::kv::main_loop(loop_store, idle_timeout, queue)
+                        .await
+                });
+
+                let kv = kv::KeyValue{
+                    task_caller,
+                    task_spawner: self.task_spawner.make_clone(py),
+                    store,
+                    context
+                };
+
+                Ok(Py::new(py, kv)?.into())
+            },
+            JetStreamCmdResponse::StreamNames{context, subject_filter} => {
+                let (task_caller, queue) =
+                    crate::task::TaskCaller::from_other(py, &self.task_caller);
+
+                self.task_spawner.spawn(async move {
+                    crate::cmds::jetstream::stream_names_loop(context, subject_filter, queue)
+                        .await
+                });
+
+                Ok(Py::new(py, list::JetStreamListIterator{task_caller})?.into())
+            },
+            JetStreamCmdResponse::Streams{context} => {
+                let (task_caller, queue) =
+                    crate::task::TaskCaller::from_other(py, &self.task_caller);
+
+                self.task_spawner.spawn(async move {
+                    crate::cmds::jetstream::list_loop(context.streams(), queue)
+                        .await
+                });
+
+                Ok(Py::new(py, list::JetStreamListIterator{task_caller})?.into())
             }
         }
     }
 }
+
+fn with_message_ttl(
+    publish: async_nats::jetstream::message::PublishMessage,
+    ttl: &Bound<PyAny>,
+) -> anyhow::Result<async_nats::jetstream::message::PublishMessage> {
+    if let Ok(ttl) = ttl.extract::<String>() {
+        if ttl != "never" {
+            anyhow::bail!("Invalid message ttl: {ttl}");
+        }
+        return Ok(publish.header(async_nats::header::NATS_MESSAGE_TTL, "never"));
+    }
+
+    let ttl = ttl.extract::<Duration>()?;
+    if ttl < Duration::from_secs(1) {
+        anyhow::bail!("Message ttl must be at least one second");
+    }
+    Ok(publish.ttl(ttl))
+}
+
+fn py_to_schedule_interval(every: &Bound<PyAny>) -> anyhow::Result<String> {
+    // Strings are passed on as is, e.g. cron expressions or "@hourly".
+    if let Ok(pattern) = every.extract::<String>() {
+        return Ok(pattern);
+    }
+
+    // The server only takes whole seconds.
+    let every = every.extract::<Duration>()?;
+    if every.is_zero() || every.subsec_nanos() != 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "Schedule interval must be a non-zero number of whole seconds"
+        ).into());
+    }
+    Ok(format!("@every {}s", every.as_secs()))
+}
@@ -0,0 +1,290 @@
+use pyo3::prelude::*;
+use std::time::Duration;
+
+use crate::bindings::converters;
+
+use crate::bindings::exceptions::map_kv_error;
+use crate::bindings::exceptions::map_timeout_error;
+use crate::cmds::kv::KeyValueCmd;
+use crate::cmds::kv::KeyValueCmdResponse;
+
+#[pyclass]
+pub(crate) struct KeyValue {
+    pub(crate) task_caller: crate::task::TaskCaller<KeyValueCmd, KeyValueCmdResponse>,
+    pub(crate) task_spawner: crate::task::TaskSpawner,
+    pub(crate) store: async_nats::jetstream::kv::Store,
+    pub(crate) context: async_nats::jetstream::Context,
+}
+
+#[pymethods]
+impl KeyValue {
+    #[getter]
+    fn bucket(&self) -> &str {
+        self.store.name.as_str()
+    }
+
+    fn get(&self, py: Python, key: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::Get { key })
+    }
+
+    fn put(&self, py: Python, key: String, value: &[u8]) -> anyhow::Result<Py<PyAny>> {
+        let value = bytes::Bytes::copy_from_slice(value);
+        self.send_command(py, KeyValueCmd::Put { key, value })
+    }
+
+    fn create(&self, py: Python, key: String, value: &[u8]) -> anyhow::Result<Py<PyAny>> {
+        let value = bytes::Bytes::copy_from_slice(value);
+        self.send_command(py, KeyValueCmd::Create { key, value })
+    }
+
+    fn update(
+        &self,
+        py: Python,
+        key: String,
+        value: &[u8],
+        revision: u64
+    ) -> anyhow::Result<Py<PyAny>> {
+        let value = bytes::Bytes::copy_from_slice(value);
+        self.send_command(py, KeyValueCmd::Update { key, value, revision })
+    }
+
+    #[pyo3(signature = (key, revision=None))]
+    fn delete(&self, py: Python, key: String, revision: Option<u64>) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::Delete { key, revision })
+    }
+
+    #[pyo3(signature = (key, revision=None))]
+    fn purge(&self, py: Python, key: String, revision: Option<u64>) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::Purge { key, revision })
+    }
+
+    #[pyo3(signature = (key, revision=None))]
+    fn entry(&self, py: Python, key: String, revision: Option<u64>) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::Entry { key, revision })
+    }
+
+    fn history(&self, py: Python, key: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::History { key })
+    }
+
+    #[pyo3(signature = (filter=None))]
+    fn keys(
+        &self,
+        py: Python,
+        filter: Option<String>
+    ) -> anyhow::Result<super::list::JetStreamListIterator> {
+        let (task_caller, queue) = crate::task::TaskCaller::from_other(py, &self.task_caller);
+
+        let store = self.store.clone();
+        let keys = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::kv::kv_keys(store).await
+        })??;
+
+        self.task_spawner.spawn(async move {
+            crate::cmds::kv::keys_loop(keys, filter, queue)
+                .await
+        });
+
+        Ok(super::list::JetStreamListIterator{task_caller})
+    }
+
+    #[pyo3(signature = (older_than=Duration::from_secs(30 * 60)))]
+    fn purge_deletes(&self, py: Python, older_than: Duration) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::PurgeDeletes { older_than })
+    }
+
+    fn status(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, KeyValueCmd::Status)
+    }
+
+    fn cache(&self, py: Python) -> anyhow::Result<KeyValueCache> {
+        let store = self.store.clone();
+        let idle_timeout = self.context.client().timeout().unwrap_or(Duration::from_secs(10));
+        let (watch, cache) = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::kv::kv_cache_load(store, idle_timeout).await
+        })??;
+
+        let task = self.task_spawner.rt_handle.spawn(
+            crate::cmds::kv::cache_loop(watch, cache.clone())
+        );
+
+        Ok(KeyValueCache{cache, task})
+    }
+
+    fn watch(&self, py: Python, key_pattern: String) -> anyhow::Result<KeyValueWatcher> {
+        self.make_watcher(py, Some(key_pattern), false)
+    }
+
+    fn watch_all(&self, py: Python) -> anyhow::Result<KeyValueWatcher> {
+        self.make_watcher(py, None, false)
+    }
+
+    #[pyo3(signature = (key_pattern=None))]
+    fn watch_with_history(
+        &self,
+        py: Python,
+        key_pattern: Option<String>
+    ) -> anyhow::Result<KeyValueWatcher> {
+        self.make_watcher(py, key_pattern, true)
+    }
+}
+
+impl KeyValue {
+    fn send_command(
+        &self,
+        py: Python,
+        command: KeyValueCmd,
+    ) -> anyhow::Result<Py<PyAny>> {
+        let response = self.task_caller.req_response(py, command)?;
+        self.response_to_py(py, response.map_err(map_kv_error)?)
+    }
+
+    fn response_to_py(
+        &self,
+        py: Python,
+        response: KeyValueCmdResponse,
+    ) -> anyhow::Result<Py<PyAny>> {
+        match response {
+            KeyValueCmdResponse::NoResponse => Ok(py.None()),
+            KeyValueCmdResponse::Value(value) => {
+                let Some(value) = value else {
+                    return Ok(py.None());
+                };
+                Ok(pyo3::types::PyBytes::new(py, &value).into_any().unbind())
+            },
+            KeyValueCmdResponse::Revision(revision) => {
+                Ok(revision.into_pyobject(py)?.into_any().unbind())
+            },
+            KeyValueCmdResponse::Entry(entry) => {
+                let Some(entry) = entry else {
+                    return Ok(py.None());
+                };
+                Ok(converters::jetstream::kv::kv_entry_to_py(py, &entry)?.into_any().unbind())
+            },
+            KeyValueCmdResponse::Entries(entries) => {
+                let py_entries = entries.iter()
+                    .map(|entry| converters::jetstream::kv::kv_entry_to_py(py, entry))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                Ok(pyo3::types::PyList::new(py, py_entries)?.into_any().unbind())
+            },
+            KeyValueCmdResponse::Status(status) => {
+                Ok(converters::jetstream::kv::kv_status_to_py(py, &status)?.into_any().unbind())
+            }
+        }
+    }
+
+    fn make_watcher(
+        &self,
+        py: Python,
+        key: Option<String>,
+        history: bool,
+    ) -> anyhow::Result<KeyValueWatcher> {
+        let (task_caller, queue) = crate::task::TaskCaller::from_other(py, &self.task_caller);
+
+        let store = self.store.clone();
+        let watch = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::kv::kv_watch(store, key, history).await
+        })??;
+
+        self.task_spawner.spawn(async move {
+            crate::cmds::kv::watch_loop(watch, queue)
+                .await
+        });
+
+        Ok(KeyValueWatcher{task_caller})
+    }
+}
+
+#[pyclass]
+pub(crate) struct KeyValueWatcher {
+    task_caller: crate::task::TaskCaller<Option<Duration>, crate::cmds::kv::WatchResult>,
+}
+
+#[pymethods]
+impl KeyValueWatcher {
+    #[pyo3(signature = (timeout=None))]
+    fn recv_entry(
+        &self,
+        py: Python,
+        timeout: Option<Duration>
+    ) -> anyhow::Result<Option<Py<PyAny>>> {
+        let Some(entry) = self.task_caller.req_response(py, timeout)?.map_err(map_timeout_error)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(converters::jetstream::kv::kv_entry_to_py(py, &entry)?.into_any().unbind()))
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python) -> anyhow::Result<Option<Py<PyAny>>> {
+        self.recv_entry(py, None)
+    }
+}
+
+/// Reads never leave Python, the entries are updated by `cache_loop`.
+#[pyclass]
+pub(crate) struct KeyValueCache {
+    cache: crate::cmds::kv::KeyValueCache,
+    task: tokio::task::JoinHandle<()>,
+}
+
+#[pymethods]
+impl KeyValueCache {
+    #[getter]
+    fn revision(&self) -> u64 {
+        self.read().revision
+    }
+
+    #[getter]
+    fn stale(&self) -> bool {
+        self.read().error.is_some()
+    }
+
+    #[getter]
+    fn error(&self) -> Option<String> {
+        self.read().error.clone()
+    }
+
+    fn get<'py>(&self, py: Python<'py>, key: &str) -> Option<Bound<'py, pyo3::types::PyBytes>> {
+        self.read()
+            .entries
+            .get(key)
+            .map(|entry| pyo3::types::PyBytes::new(py, &entry.value))
+    }
+
+    fn entry(&self, py: Python, key: &str) -> anyhow::Result<Option<Py<PyAny>>> {
+        let cache = self.read();
+        let Some(entry) = cache.entries.get(key) else {
+            return Ok(None);
+        };
+
+        Ok(Some(converters::jetstream::kv::kv_entry_to_py(py, entry)?.into_any().unbind()))
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.read().entries.keys().cloned().collect()
+    }
+
+    fn __len__(&self) -> usize {
+        self.read().entries.len()
+    }
+
+    fn __contains__(&self, key: &str) -> bool {
+        self.read().entries.contains_key(key)
+    }
+}
+
+impl KeyValueCache {
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, crate::cmds::kv::KeyValueCacheState> {
+        self.cache.read().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+impl Drop for KeyValueCache {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,33 @@
+use pyo3::prelude::*;
+
+use crate::bindings::converters;
+use crate::cmds::jetstream::JetStreamListItem;
+use crate::cmds::jetstream::JetStreamListResult;
+
+#[pyclass]
+pub(crate) struct JetStreamListIterator {
+    pub(crate) task_caller: crate::task::TaskCaller<(), JetStreamListResult>,
+}
+
+#[pymethods]
+impl JetStreamListIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python) -> anyhow::Result<Option<Py<PyAny>>> {
+        let Some(item) = self.task_caller.req_response(py, ())?? else {
+            return Ok(None);
+        };
+
+        let py_item = match item {
+            JetStreamListItem::Name(name) => name.into_pyobject(py)?.into_any(),
+            JetStreamListItem::StreamInfo(info) =>
+                converters::jetstream::stream::stream_info_to_py(py, &info, None)?.into_any(),
+            JetStreamListItem::ConsumerInfo(info) =>
+                converters::jetstream::consumer::consumer_info_to_py(py, &info)?.into_any(),
+        };
+
+        Ok(Some(py_item.unbind()))
+    }
+}
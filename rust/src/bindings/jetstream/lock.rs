@@ -0,0 +1,268 @@
+use pyo3::prelude::*;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+
+use crate::bindings::exceptions::map_timeout_error;
+use crate::bindings::jetstream::kv::KeyValue;
+
+/// Value written to the key, so that every holder is told apart.
+fn make_token() -> bytes::Bytes {
+    bytes::Bytes::from(nuid::next().to_string())
+}
+
+/// TTLs are sent in whole seconds.
+fn check_ttl(ttl: Duration) -> anyhow::Result<()> {
+    if ttl < Duration::from_secs(1) {
+        return Err(pyo3::exceptions::PyValueError::new_err("ttl must be at least one second").into());
+    }
+    Ok(())
+}
+
+#[pyclass]
+pub(crate) struct Lock {
+    task_spawner: crate::task::TaskSpawner,
+    store: async_nats::jetstream::kv::Store,
+    context: async_nats::jetstream::Context,
+    key: String,
+    ttl: Duration,
+    token: bytes::Bytes,
+    revision: Mutex<Option<u64>>,
+}
+
+#[pymethods]
+impl Lock {
+    #[new]
+    fn new(
+        py: Python,
+        kv: PyRef<'_, KeyValue>,
+        key: String,
+        ttl: Duration
+    ) -> anyhow::Result<Self> {
+        check_ttl(ttl)?;
+
+        Ok(Lock {
+            task_spawner: kv.task_spawner.make_clone(py),
+            store: kv.store.clone(),
+            context: kv.context.clone(),
+            key,
+            ttl,
+            token: make_token(),
+            revision: Mutex::new(None),
+        })
+    }
+
+    #[getter]
+    fn key(&self) -> &str {
+        self.key.as_str()
+    }
+
+    #[getter]
+    fn locked(&self) -> bool {
+        self.revision().is_some()
+    }
+
+    #[getter]
+    fn get_revision(&self) -> Option<u64> {
+        self.revision()
+    }
+
+    #[pyo3(signature = (timeout=None))]
+    fn acquire(&self, py: Python, timeout: Option<Duration>) -> anyhow::Result<bool> {
+        if self.revision().is_some() {
+            return Err(pyo3::exceptions::PyRuntimeError::new_err("Lock is already held").into());
+        }
+
+        let (store, key, token, ttl) =
+            (self.store.clone(), self.key.clone(), self.token.clone(), self.ttl);
+        let revision = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::lock::lock_acquire(store, key, token, ttl, timeout).await
+        })??;
+
+        self.set_revision(revision);
+        Ok(revision.is_some())
+    }
+
+    fn renew(&self, py: Python) -> anyhow::Result<bool> {
+        let Some(revision) = self.revision() else {
+            return Ok(false);
+        };
+
+        let (store, context, key, token, ttl) = (
+            self.store.clone(), self.context.clone(), self.key.clone(), self.token.clone(), self.ttl
+        );
+        let revision = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::lock::lock_renew(store, context, key, token, revision, ttl).await
+        })??;
+
+        self.set_revision(revision);
+        Ok(revision.is_some())
+    }
+
+    fn release(&self, py: Python) -> anyhow::Result<bool> {
+        let Some(revision) = self.revision() else {
+            return Ok(false);
+        };
+
+        let (store, key) = (self.store.clone(), self.key.clone());
+        let released = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::lock::lock_release(store, key, revision).await
+        })??;
+
+        self.set_revision(None);
+        Ok(released)
+    }
+
+    fn __enter__<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> anyhow::Result<PyRef<'py, Self>> {
+        slf.acquire(py, None)?;
+        Ok(slf)
+    }
+
+    fn __exit__(
+        &self,
+        py: Python,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> anyhow::Result<()> {
+        self.release(py)?;
+        Ok(())
+    }
+}
+
+impl Lock {
+    fn revision(&self) -> Option<u64> {
+        *self.revision.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn set_revision(&self, revision: Option<u64>) {
+        *self.revision.lock().unwrap_or_else(PoisonError::into_inner) = revision;
+    }
+}
+
+/// The election runs in `election_loop`, Python only reads its state and
+/// waits for changes.
+#[pyclass]
+pub(crate) struct LeaderElection {
+    task_caller: crate::task::TaskCaller<Option<Duration>, crate::cmds::lock::LeaderChange>,
+    task_spawner: crate::task::TaskSpawner,
+    store: async_nats::jetstream::kv::Store,
+    key: String,
+    leader: crate::cmds::lock::Leader,
+    task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+    stop: std::sync::Arc<tokio::sync::Notify>,
+    changes: Mutex<Option<tokio::sync::mpsc::UnboundedSender<bool>>>,
+}
+
+#[pymethods]
+impl LeaderElection {
+    #[new]
+    fn new(
+        py: Python,
+        kv: PyRef<'_, KeyValue>,
+        key: String,
+        ttl: Duration
+    ) -> anyhow::Result<Self> {
+        check_ttl(ttl)?;
+
+        let (task_caller, queue) = crate::task::TaskCaller::from_other(py, &kv.task_caller);
+        let (changes_sender, changes) = tokio::sync::mpsc::unbounded_channel();
+        let leader = crate::cmds::lock::Leader::default();
+        let stop = std::sync::Arc::new(tokio::sync::Notify::new());
+
+        kv.task_spawner.spawn(async move {
+            crate::cmds::lock::changes_loop(changes, queue)
+                .await
+        });
+
+        let candidate = crate::cmds::lock::Candidate {
+            store: kv.store.clone(),
+            context: kv.context.clone(),
+            key: key.clone(),
+            token: make_token(),
+            ttl,
+        };
+        let task = kv.task_spawner.rt_handle.spawn(crate::cmds::lock::election_loop(
+            candidate,
+            leader.clone(),
+            changes_sender.clone(),
+            stop.clone(),
+        ));
+
+        Ok(LeaderElection {
+            task_caller,
+            task_spawner: kv.task_spawner.make_clone(py),
+            store: kv.store.clone(),
+            key,
+            leader,
+            task: Mutex::new(Some(task)),
+            stop,
+            changes: Mutex::new(Some(changes_sender)),
+        })
+    }
+
+    #[getter]
+    fn key(&self) -> &str {
+        self.key.as_str()
+    }
+
+    #[getter]
+    fn is_leader(&self) -> bool {
+        self.leader.lock().unwrap_or_else(PoisonError::into_inner).revision.is_some()
+    }
+
+    #[getter]
+    fn error(&self) -> Option<String> {
+        self.leader.lock().unwrap_or_else(PoisonError::into_inner).error.clone()
+    }
+
+    #[pyo3(signature = (timeout=None))]
+    fn recv_change(&self, py: Python, timeout: Option<Duration>) -> anyhow::Result<Option<bool>> {
+        self.task_caller.req_response(py, timeout)?.map_err(map_timeout_error)
+    }
+
+    fn resign(&self, py: Python) -> anyhow::Result<()> {
+        let task = self.task.lock().unwrap_or_else(PoisonError::into_inner).take();
+        let Some(task) = task else {
+            return Ok(());
+        };
+
+        // Let the task finish a renewal in flight, so that the revision is
+        // the latest one.
+        self.stop.notify_one();
+        let _ = self.task_spawner.spawn_blocking(py, task)?;
+
+        let revision = self.leader.lock().unwrap_or_else(PoisonError::into_inner).revision.take();
+        // Dropping the last sender ends the iteration over the changes.
+        let changes = self.changes.lock().unwrap_or_else(PoisonError::into_inner).take();
+        let Some(revision) = revision else {
+            return Ok(());
+        };
+
+        if let Some(changes) = changes {
+            let _ = changes.send(false);
+        }
+
+        let (store, key) = (self.store.clone(), self.key.clone());
+        self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::lock::lock_release(store, key, revision).await
+        })??;
+        Ok(())
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&self, py: Python) -> anyhow::Result<Option<bool>> {
+        self.recv_change(py, None)
+    }
+}
+
+impl Drop for LeaderElection {
+    fn drop(&mut self) {
+        if let Some(task) = self.task.lock().unwrap_or_else(PoisonError::into_inner).take() {
+            task.abort();
+        }
+    }
+}
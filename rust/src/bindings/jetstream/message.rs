@@ -0,0 +1,123 @@
+use pyo3::prelude::*;
+use std::time::Duration;
+
+use async_nats::jetstream::AckKind;
+
+use crate::bindings::converters::headermap::HeaderMap;
+use crate::bindings::converters::offset_datetime::offset_datetime_to_py;
+
+#[pyclass]
+pub(crate) struct JetStreamMessage {
+    pub(crate) task_spawner: crate::task::TaskSpawner,
+    pub(crate) message: async_nats::jetstream::Message,
+}
+
+#[pymethods]
+impl JetStreamMessage {
+    #[getter]
+    fn subject(&self) -> &str {
+        self.message.subject.as_str()
+    }
+
+    #[getter]
+    fn reply(&self) -> Option<&str> {
+        self.message.reply.as_ref().map(|reply| reply.as_str())
+    }
+
+    #[getter]
+    fn payload(&self) -> &[u8] {
+        self.message.payload.as_ref()
+    }
+
+    #[getter]
+    fn headers(&self) -> Option<HeaderMap> {
+        self.message.headers.clone().map(HeaderMap::new)
+    }
+
+    #[getter]
+    fn status(&self) -> Option<u16> {
+        self.message.status.map(|status| status.as_u16())
+    }
+
+    #[getter]
+    fn description(&self) -> Option<&str> {
+        self.message.description.as_deref()
+    }
+
+    #[getter]
+    fn marker_reason(&self) -> Option<&str> {
+        self.message
+            .headers
+            .as_ref()
+            .and_then(|headers| headers.get(async_nats::header::NATS_MARKER_REASON))
+            .map(|reason| reason.as_str())
+    }
+
+    #[getter]
+    fn is_delete_marker(&self) -> bool {
+        self.marker_reason().is_some()
+    }
+
+    fn info(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        let info = self.message.info().map_err(|err| anyhow::anyhow!("{err}"))?;
+
+        let py_result = pyo3::types::PyDict::new(py);
+        py_result.set_item("domain", info.domain)?;
+        py_result.set_item("stream", info.stream)?;
+        py_result.set_item("consumer", info.consumer)?;
+        py_result.set_item("stream_sequence", info.stream_sequence)?;
+        py_result.set_item("consumer_sequence", info.consumer_sequence)?;
+        py_result.set_item("delivered", info.delivered)?;
+        py_result.set_item("pending", info.pending)?;
+        py_result.set_item("published", offset_datetime_to_py(py, info.published)?)?;
+
+        Ok(py_result.into())
+    }
+
+    fn ack(&self, py: Python) -> anyhow::Result<()> {
+        self.send_ack(py, AckKind::Ack)
+    }
+
+    #[pyo3(signature = (delay=None))]
+    fn nak(&self, py: Python, delay: Option<Duration>) -> anyhow::Result<()> {
+        self.send_ack(py, AckKind::Nak(delay))
+    }
+
+    fn in_progress(&self, py: Python) -> anyhow::Result<()> {
+        self.send_ack(py, AckKind::Progress)
+    }
+
+    #[pyo3(signature = (reason=None))]
+    fn term(&self, py: Python, reason: Option<String>) -> anyhow::Result<()> {
+        let Some(reason) = reason else {
+            return self.send_ack(py, AckKind::Term);
+        };
+
+        // AckKind::Term carries no reason, so the payload is built by hand.
+        let Some(reply) = self.message.reply.clone() else {
+            anyhow::bail!("No reply subject, not a JetStream message");
+        };
+        let client = self.message.context.client();
+        let payload = bytes::Bytes::from(format!("+TERM {reason}"));
+
+        self.task_spawner
+            .spawn_blocking(py, async move { client.publish(reply, payload).await })?
+            .map_err(|err| anyhow::anyhow!("{err}"))
+    }
+
+    fn double_ack(&self, py: Python) -> anyhow::Result<()> {
+        let message = self.message.clone();
+        self.task_spawner
+            .spawn_blocking(py, async move { message.double_ack().await })?
+            .map_err(|err| anyhow::anyhow!("{err}"))
+    }
+}
+
+impl JetStreamMessage {
+    fn send_ack(&self, py: Python, kind: AckKind) -> anyhow::Result<()> {
+        let message = self.message.clone();
+        self.task_spawner
+            .spawn_blocking(py, async move { message.ack_with(kind).await })?
+            .map_err(|err| anyhow::anyhow!("{err}"))
+    }
+}
@@ -1,30 +1,175 @@
 use pyo3::prelude::*;
+use std::time::Duration;
+
+use tokio::sync::oneshot;
+use tokio::time::timeout as tokio_timeout;
+
+use async_nats::jetstream::context::PublishError;
+use async_nats::jetstream::publish::PublishAck;
+
+use crate::bindings::exceptions::map_publish_error;
+
+type PublishAckResult = Result<PublishAck, PublishError>;
+
+enum PublishAckState {
+    Pending(oneshot::Receiver<PublishAckResult>),
+    Done(PublishAckResult),
+    Consumed,
+}
 
 #[pyclass]
 pub(crate) struct PublishAckFuture {
-    pub(crate) task_spawner: crate::task::TaskSpawner,
-    pub(crate) future: Option<async_nats::jetstream::context::PublishAckFuture>
+    task_spawner: crate::task::TaskSpawner,
+    state: PublishAckState,
+}
+
+impl PublishAckFuture {
+    /// Awaits the ack in the runtime right away. This releases the in-flight
+    /// slot as soon as the ack arrives, even if Python never waits for it.
+    pub(crate) fn spawn(
+        py: Python,
+        task_spawner: &crate::task::TaskSpawner,
+        future: async_nats::jetstream::context::PublishAckFuture,
+    ) -> Self {
+        let (sender, receiver) = oneshot::channel();
+        task_spawner.spawn(async move {
+            let _ = sender.send(future.await);
+        });
+
+        PublishAckFuture {
+            task_spawner: task_spawner.make_clone(py),
+            state: PublishAckState::Pending(receiver),
+        }
+    }
+
+    fn take_result(&mut self) -> Option<PublishAckResult> {
+        match std::mem::replace(&mut self.state, PublishAckState::Consumed) {
+            PublishAckState::Done(result) => Some(result),
+            state => {
+                self.state = state;
+                None
+            }
+        }
+    }
 }
 
 #[pymethods]
 impl PublishAckFuture {
-    fn wait(&mut self, py: Python) -> anyhow::Result<Py<PyAny>> {
-        let Some(future) = self.future.take() else {
+    #[pyo3(signature = (timeout=None))]
+    fn wait(&mut self, py: Python, timeout: Option<Duration>) -> anyhow::Result<Py<PyAny>> {
+        self.done();
+        if let Some(result) = self.take_result() {
+            return publish_ack_to_py(py, result);
+        }
+
+        let PublishAckState::Pending(mut receiver) =
+            std::mem::replace(&mut self.state, PublishAckState::Consumed) else {
             return Ok(py.None());
         };
 
-        let response = self.task_spawner.spawn_blocking(
-            py,
-            future.into_future()
-        )??;
-
-        let py_result = pyo3::types::PyDict::new(py);
-        py_result.set_item("stream", response.stream.as_str())?;
-        py_result.set_item("sequence", response.sequence)?;
-        py_result.set_item("domain", response.domain.as_str())?;
-        py_result.set_item("duplicate", response.duplicate)?;
-        py_result.set_item("value", response.value)?;
-        
-        Ok(py_result.into())
-    }
-}
\ No newline at end of file
+        let result = self.task_spawner.spawn_blocking(py, async move {
+            let result = match timeout {
+                Some(timeout) => tokio_timeout(timeout, &mut receiver).await.ok(),
+                None => Some((&mut receiver).await),
+            };
+            (result, receiver)
+        })?;
+
+        match result {
+            (Some(result), _) => publish_ack_to_py(py, result?),
+            (None, receiver) => {
+                self.state = PublishAckState::Pending(receiver);
+                Err(pyo3::exceptions::PyTimeoutError::new_err("Publish ack timed out").into())
+            }
+        }
+    }
+
+    fn done(&mut self) -> bool {
+        let PublishAckState::Pending(receiver) = &mut self.state else {
+            return true;
+        };
+
+        match receiver.try_recv() {
+            Ok(result) => {
+                self.state = PublishAckState::Done(result);
+                true
+            },
+            Err(oneshot::error::TryRecvError::Empty) => false,
+            Err(oneshot::error::TryRecvError::Closed) => {
+                self.state = PublishAckState::Consumed;
+                true
+            }
+        }
+    }
+}
+
+/// Waits for all futures in a single round trip to the runtime. A failed
+/// ack does not stop the others, its exception is returned in its place.
+pub(crate) fn wait_all(
+    py: Python,
+    task_spawner: &crate::task::TaskSpawner,
+    futures: Vec<Py<PublishAckFuture>>,
+) -> anyhow::Result<Py<PyAny>> {
+    // Borrow all futures first, so that none is consumed if one is in use.
+    let mut borrowed = Vec::with_capacity(futures.len());
+    for future in futures.iter() {
+        let future = future.try_borrow_mut(py).map_err(|_| {
+            pyo3::exceptions::PyRuntimeError::new_err("PublishAckFuture is already being waited")
+        })?;
+        borrowed.push(future);
+    }
+
+    let mut receivers = Vec::with_capacity(borrowed.len());
+    let mut states = Vec::with_capacity(borrowed.len());
+    for (index, mut future) in borrowed.into_iter().enumerate() {
+        future.done();
+        match std::mem::replace(&mut future.state, PublishAckState::Consumed) {
+            PublishAckState::Pending(receiver) => receivers.push(receiver),
+            state => states.push((index, state)),
+        }
+    }
+
+    let received = task_spawner.spawn_blocking(py, async move {
+        let mut received = Vec::with_capacity(receivers.len());
+        for receiver in receivers {
+            received.push(receiver.await.ok());
+        }
+        received
+    })?;
+
+    // Put the already finished futures back at their positions.
+    let mut results: Vec<Option<PublishAckResult>> = received;
+    for (index, state) in states {
+        let result = match state {
+            PublishAckState::Done(result) => Some(result),
+            _ => None,
+        };
+        results.insert(index, result);
+    }
+
+    let py_results = pyo3::types::PyList::empty(py);
+    for result in results {
+        match result {
+            Some(result) => match publish_ack_to_py(py, result) {
+                Ok(ack) => py_results.append(ack)?,
+                Err(err) => py_results.append(PyErr::from(err).into_value(py))?,
+            },
+            None => py_results.append(py.None())?,
+        }
+    }
+
+    Ok(py_results.into_any().unbind())
+}
+
+fn publish_ack_to_py(py: Python, result: PublishAckResult) -> anyhow::Result<Py<PyAny>> {
+    let response = result.map_err(|err| map_publish_error(err.into()))?;
+
+    let py_result = pyo3::types::PyDict::new(py);
+    py_result.set_item("stream", response.stream.as_str())?;
+    py_result.set_item("sequence", response.sequence)?;
+    py_result.set_item("domain", response.domain.as_str())?;
+    py_result.set_item("duplicate", response.duplicate)?;
+    py_result.set_item("value", response.value)?;
+
+    Ok(py_result.into())
+}
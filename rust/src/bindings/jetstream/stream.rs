@@ -1,66 +1,543 @@
 use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
 
 use crate::task::TaskCaller;
+use crate::cmds::jetstream::JetStreamStreamCmd;
+use crate::cmds::jetstream::JetStreamStreamCmdResponse;
+use crate::cmds::jetstream::PullConsumerFetch;
+use crate::cmds::jetstream::PullConsumerPriorityGroup;
+use crate::cmds::jetstream::PullConsumerStream;
+use crate::bindings::exceptions::map_messages_error;
 use crate::bindings::converters;
-use crate::bindings::converters::message::Message;
+use crate::bindings::jetstream::list::JetStreamListIterator;
+use crate::bindings::jetstream::message::JetStreamMessage;
 
 
 #[pyclass]
 pub(crate) struct JetStreamStream {
-    pub(crate) task_caller: TaskCaller<crate::cmds::jetstream::JetStreamStreamCmd, crate::cmds::jetstream::JetStreamStreamCmdResponse>,
-    pub(crate) task_spawner: crate::task::TaskSpawner
+    pub(crate) task_caller: TaskCaller<JetStreamStreamCmd, JetStreamStreamCmdResponse>,
+    pub(crate) task_spawner: crate::task::TaskSpawner,
+    pub(crate) context: async_nats::jetstream::Context
 }
 
 #[pymethods]
 impl JetStreamStream {
 
     fn get_consumer(&self, py: Python, name: String, typename: String, ordered: bool) -> anyhow::Result<Py<PyAny>> {
-        let consumer: Py<PyAny> = match (typename.as_str(), ordered) {
-            ("pull", false) => {
-                let result = self.task_caller.req_response(
-                    py, crate::cmds::jetstream::JetStreamStreamCmd::GetPullConsumer { name }
-                )?;
-
-                let crate::cmds::jetstream::JetStreamStreamCmdResponse::PullConsumer(consumer) = result?;
-                let consumer = JetStreamPullConsumer{consumer, task_spawner: self.task_spawner.make_clone(py)};
-                Py::new(py, consumer)?.into()
-            },
+        let command = match (typename.as_str(), ordered) {
+            ("pull", false) => JetStreamStreamCmd::GetPullConsumer { name },
+            ("pull", true) => anyhow::bail!(
+                "Ordered consumers are created by the client. Use get_or_create_consumer"
+            ),
+            ("push", false) => JetStreamStreamCmd::GetPushConsumer { name },
             (t, o) => anyhow::bail!("Unsupported Consumer. Type:{t} Order:{o}")
         };
 
-        Ok(consumer)
+        self.send_command(py, command)
     }
     fn get_or_create_consumer(&self, py: Python, name: String, typename: String, ordered: bool, config: &Bound<pyo3::types::PyDict>) -> anyhow::Result<Py<PyAny>> {
-        let consumer_config = converters::jetstream::consumer::py_to_consumer_config(config)?;
-        let consumer: Py<PyAny> = match (typename.as_str(), ordered) {
-            ("pull", false) => {
-                let result = self.task_caller.req_response(
-                    py, crate::cmds::jetstream::JetStreamStreamCmd::GetOrCreatePullConsumer{
-                        name,
-                        config: consumer_config
-                    }
-                )?;
-
-                let crate::cmds::jetstream::JetStreamStreamCmdResponse::PullConsumer(consumer) = result?;
-                let consumer = JetStreamPullConsumer{consumer, task_spawner: self.task_spawner.make_clone(py)};
-                Py::new(py, consumer)?.into()
+        let command = match (typename.as_str(), ordered) {
+            ("pull", false) => JetStreamStreamCmd::GetOrCreatePullConsumer{
+                name,
+                config: converters::jetstream::consumer::py_to_consumer_config(config)?
+            },
+            ("pull", true) => {
+                let mut config =
+                    converters::jetstream::consumer::py_to_ordered_consumer_config(config)?;
+                config.name = Some(name);
+                JetStreamStreamCmd::CreateOrderedConsumer { config }
+            },
+            ("push", false) => JetStreamStreamCmd::GetOrCreatePushConsumer{
+                name,
+                config: converters::jetstream::consumer::py_to_push_consumer_config(config)?
             },
             (t, o) => anyhow::bail!("Unsupported Consumer. Type:{t} Order:{o}")
         };
 
-        Ok(consumer)
+        self.send_command(py, command)
+    }
+
+    fn consumer_info(&self, py: Python, name: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::ConsumerInfo { name })
+    }
+
+    fn delete_consumer(&self, py: Python, name: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::DeleteConsumer { name })
+    }
+
+    fn pause_consumer(
+        &self,
+        py: Python,
+        name: String,
+        until: &Bound<PyAny>
+    ) -> anyhow::Result<Py<PyAny>> {
+        let until = converters::offset_datetime::py_to_offset_datetime(until)?;
+        self.send_command(py, JetStreamStreamCmd::PauseConsumer { name, until })
+    }
+
+    fn resume_consumer(&self, py: Python, name: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::ResumeConsumer { name })
+    }
+
+    #[pyo3(signature = (subjects_filter=None))]
+    fn info(&self, py: Python, subjects_filter: Option<String>) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::Info { subjects_filter })
+    }
+
+    #[pyo3(signature = (filter=None, keep=None, sequence=None))]
+    fn purge(
+        &self,
+        py: Python,
+        filter: Option<String>,
+        keep: Option<u64>,
+        sequence: Option<u64>
+    ) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::Purge { filter, keep, sequence })
+    }
+
+    fn get_message(&self, py: Python, sequence: u64) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::GetMessage { sequence })
+    }
+
+    fn get_last_message(&self, py: Python, subject: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::GetLastMessage { subject })
+    }
+
+    fn delete_message(&self, py: Python, sequence: u64) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::DeleteMessage { sequence, erase: false })
+    }
+
+    fn erase_message(&self, py: Python, sequence: u64) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::DeleteMessage { sequence, erase: true })
+    }
+
+    fn direct_get(&self, py: Python, sequence: u64) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::DirectGet { sequence })
+    }
+
+    fn direct_get_last_for_subject(
+        &self,
+        py: Python,
+        subject: String
+    ) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::DirectGetLastForSubject { subject })
+    }
+
+    #[pyo3(signature = (subject, after_seq=None))]
+    fn direct_get_next_for_subject(
+        &self,
+        py: Python,
+        subject: String,
+        after_seq: Option<u64>
+    ) -> anyhow::Result<Py<PyAny>> {
+        // The server returns the first message at or above the sequence.
+        let sequence = after_seq.map(|sequence| sequence + 1);
+        self.send_command(py, JetStreamStreamCmd::DirectGetNextForSubject { subject, sequence })
+    }
+
+    #[pyo3(signature = (subjects, up_to_sequence=None, up_to_time=None))]
+    fn direct_get_multi_last(
+        &self,
+        py: Python,
+        subjects: Vec<String>,
+        up_to_sequence: Option<u64>,
+        up_to_time: Option<&Bound<PyAny>>
+    ) -> anyhow::Result<Py<PyAny>> {
+        let up_to_time = up_to_time
+            .map(converters::offset_datetime::py_to_offset_datetime)
+            .transpose()?;
+        let command = JetStreamStreamCmd::DirectGetMultiLast {
+            subjects,
+            up_to_sequence,
+            up_to_time
+        };
+        self.send_command(py, command)
+    }
+
+    #[pyo3(signature = (subject_filter=">".to_string()))]
+    fn schedules(&self, py: Python, subject_filter: String) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::Schedules { subject_filter })
+    }
+
+    fn cancel_schedule(&self, py: Python, subject: String) -> anyhow::Result<bool> {
+        // The server drops a schedule once its message is gone.
+        let command = JetStreamStreamCmd::Purge {
+            filter: Some(subject),
+            keep: None,
+            sequence: None
+        };
+        match self.task_caller.req_response(py, command)?? {
+            JetStreamStreamCmdResponse::PurgeStatus { purged } => Ok(purged > 0),
+            response => anyhow::bail!("Unexpected stream response: {response:?}"),
+        }
+    }
+
+    fn consumer_names(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::ConsumerNames)
+    }
+
+    fn consumers(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        self.send_command(py, JetStreamStreamCmd::Consumers)
+    }
+}
+
+impl JetStreamStream {
+    fn send_command(
+        &self,
+        py: Python,
+        command: JetStreamStreamCmd,
+    ) -> anyhow::Result<Py<PyAny>> {
+        let response = self.task_caller.req_response(py, command)?;
+        self.response_to_py(py, response?)
+    }
+
+    fn response_to_py(
+        &self,
+        py: Python,
+        response: JetStreamStreamCmdResponse,
+    ) -> anyhow::Result<Py<PyAny>> {
+        match response {
+            JetStreamStreamCmdResponse::PullConsumer(consumer) => {
+                let consumer = JetStreamPullConsumer{
+                    consumer,
+                    task_spawner: self.task_spawner.make_clone(py),
+                    stream_task_caller: self.task_caller.make_clone(py),
+                    context: self.context.clone(),
+                    pin_ids: Mutex::new(HashMap::new())
+                };
+                Ok(Py::new(py, consumer)?.into())
+            },
+            JetStreamStreamCmdResponse::OrderedConsumer(consumer) => {
+                let consumer = JetStreamOrderedConsumer{consumer, task_spawner: self.task_spawner.make_clone(py)};
+                Ok(Py::new(py, consumer)?.into())
+            },
+            JetStreamStreamCmdResponse::PushConsumer(consumer) => {
+                let consumer = JetStreamPushConsumer{
+                    consumer,
+                    task_spawner: self.task_spawner.make_clone(py),
+                    stream_task_caller: self.task_caller.make_clone(py)
+                };
+                Ok(Py::new(py, consumer)?.into())
+            },
+            JetStreamStreamCmdResponse::ConsumerNames(stream) => {
+                let (task_caller, queue) = TaskCaller::from_other(py, &self.task_caller);
+
+                self.task_spawner.spawn(async move {
+                    crate::cmds::jetstream::list_loop(stream.consumer_names(), queue)
+                        .await
+                });
+
+                Ok(Py::new(py, JetStreamListIterator{task_caller})?.into())
+            },
+            JetStreamStreamCmdResponse::Consumers(stream) => {
+                let (task_caller, queue) = TaskCaller::from_other(py, &self.task_caller);
+
+                self.task_spawner.spawn(async move {
+                    crate::cmds::jetstream::list_loop(stream.consumers(), queue)
+                        .await
+                });
+
+                Ok(Py::new(py, JetStreamListIterator{task_caller})?.into())
+            },
+            response => stream_response_to_py(py, response)
+        }
+    }
+}
+
+fn send_stream_command(
+    py: Python,
+    task_caller: &TaskCaller<JetStreamStreamCmd, JetStreamStreamCmdResponse>,
+    command: JetStreamStreamCmd,
+) -> anyhow::Result<Py<PyAny>> {
+    let response = task_caller.req_response(py, command)?;
+    stream_response_to_py(py, response?)
+}
+
+fn stream_response_to_py(
+    py: Python,
+    response: JetStreamStreamCmdResponse,
+) -> anyhow::Result<Py<PyAny>> {
+    match response {
+        JetStreamStreamCmdResponse::ConsumerInfo(info) => {
+            Ok(converters::jetstream::consumer::consumer_info_to_py(py, &info)?.into())
+        },
+        JetStreamStreamCmdResponse::Info { info, subjects } => {
+            let py_info =
+                converters::jetstream::stream::stream_info_to_py(py, &info, subjects.as_ref())?;
+            Ok(py_info.into())
+        },
+        JetStreamStreamCmdResponse::StreamMessage(message) => {
+            Ok(converters::message::StreamMessage::new(*message).into_pyobject(py)?.unbind())
+        },
+        JetStreamStreamCmdResponse::StreamMessages(messages) => {
+            let py_messages = pyo3::types::PyList::empty(py);
+            for message in messages {
+                py_messages.append(converters::message::StreamMessage::new(message))?;
+            }
+            Ok(py_messages.into_any().unbind())
+        },
+        JetStreamStreamCmdResponse::Schedules(messages) => {
+            let py_schedules = pyo3::types::PyList::empty(py);
+            for message in messages {
+                let schedule = message.headers.get(crate::cmds::jetstream::NATS_SCHEDULE)
+                    .map(|value| value.to_string());
+                let target = message.headers.get(crate::cmds::jetstream::NATS_SCHEDULE_TARGET)
+                    .map(|value| value.to_string());
+
+                let py_schedule = converters::message::StreamMessage::new(message)
+                    .into_pyobject(py)?
+                    .cast_into::<pyo3::types::PyDict>()
+                    .map_err(|err| anyhow::anyhow!("{err}"))?;
+                py_schedule.set_item("schedule", schedule)?;
+                py_schedule.set_item("target", target)?;
+                py_schedules.append(py_schedule)?;
+            }
+            Ok(py_schedules.into_any().unbind())
+        },
+        JetStreamStreamCmdResponse::PurgeStatus { purged } => {
+            Ok(purged.into_pyobject(py)?.into_any().unbind())
+        },
+        JetStreamStreamCmdResponse::DeleteStatus { success } => {
+            Ok(success.into_pyobject(py)?.to_owned().into())
+        },
+        JetStreamStreamCmdResponse::PauseStatus { paused, pause_until, pause_remaining } => {
+            let py_result = pyo3::types::PyDict::new(py);
+            py_result.set_item("paused", paused)?;
+            // The server sends the zero time of Go, 0001-01-01, once resumed.
+            let pause_until = match pause_until.year() {
+                1 => None,
+                _ => Some(converters::offset_datetime::offset_datetime_to_py(py, pause_until)?),
+            };
+            py_result.set_item("pause_until", pause_until)?;
+            py_result.set_item("pause_remaining", pause_remaining)?;
+            Ok(py_result.into())
+        },
+        response => anyhow::bail!("Unexpected stream response: {response:?}")
     }
 }
 
 #[pyclass]
 struct JetStreamPullConsumer {
     task_spawner: crate::task::TaskSpawner,
-    consumer: async_nats::jetstream::consumer::PullConsumer
+    stream_task_caller: TaskCaller<JetStreamStreamCmd, JetStreamStreamCmdResponse>,
+    consumer: async_nats::jetstream::consumer::PullConsumer,
+    context: async_nats::jetstream::Context,
+    /// Pin ids of pinned client groups by group, sent with every fetch and batch.
+    pin_ids: Mutex<HashMap<String, String>>
 }
 
 #[pymethods]
 impl JetStreamPullConsumer {
-    fn make_receiver(&self, py: Python) -> anyhow::Result<JetStreamPullConsumerMessages> {
+    #[pyo3(signature = (
+        max_messages=None,
+        max_bytes=None,
+        expires=None,
+        heartbeat=None,
+        threshold_messages=None,
+        threshold_bytes=None,
+        group=None,
+        min_pending=None,
+        min_ack_pending=None,
+        priority=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn make_receiver(
+        &self,
+        py: Python,
+        max_messages: Option<usize>,
+        max_bytes: Option<usize>,
+        expires: Option<Duration>,
+        heartbeat: Option<Duration>,
+        threshold_messages: Option<usize>,
+        threshold_bytes: Option<usize>,
+        group: Option<String>,
+        min_pending: Option<usize>,
+        min_ack_pending: Option<usize>,
+        priority: Option<usize>
+    ) -> anyhow::Result<JetStreamConsumerMessages> {
+        // A threshold at or above the batch asks for more on every message.
+        if threshold_messages.is_some_and(|threshold| threshold >= max_messages.unwrap_or(200)) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "threshold_messages must be less than max_messages"
+            ).into());
+        }
+        if threshold_bytes.is_some_and(|threshold| threshold >= max_bytes.unwrap_or_default()) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "threshold_bytes must be less than max_bytes"
+            ).into());
+        }
+
+        let (task_caller, queue) =
+            crate::task::TaskCaller::new(self.task_spawner.read_eventfd.clone_ref(py));
+
+        let (context, consumer) = (self.context.clone(), self.consumer.clone());
+        let options = PullConsumerStream{
+            max_messages,
+            max_bytes,
+            expires,
+            heartbeat,
+            threshold_messages,
+            threshold_bytes,
+            priority_group: PullConsumerPriorityGroup{group, min_pending, min_ack_pending},
+            priority
+        };
+        let messages = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::jetstream::pull_consumer_stream(context, consumer, options).await
+        })??;
+
+        self.task_spawner.spawn(async move {
+            crate::cmds::jetstream::pull_messages_loop(messages, queue)
+                .await
+        });
+
+        Ok(JetStreamConsumerMessages{
+            task_caller,
+            task_spawner: self.task_spawner.make_clone(py)
+        })
+    }
+
+    #[pyo3(signature = (
+        max_messages,
+        expires=None,
+        max_bytes=None,
+        no_wait=false,
+        group=None,
+        min_pending=None,
+        min_ack_pending=None,
+        priority=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn fetch(
+        &self,
+        py: Python,
+        max_messages: usize,
+        expires: Option<Duration>,
+        max_bytes: Option<usize>,
+        no_wait: bool,
+        group: Option<String>,
+        min_pending: Option<usize>,
+        min_ack_pending: Option<usize>,
+        priority: Option<usize>
+    ) -> anyhow::Result<Vec<JetStreamMessage>> {
+        let fetch = PullConsumerFetch{
+            max_messages,
+            max_bytes,
+            expires,
+            no_wait,
+            priority_group: PullConsumerPriorityGroup{group, min_pending, min_ack_pending},
+            priority,
+            pin_id: None
+        };
+        self.run_fetch(py, fetch)
+    }
+
+    #[pyo3(signature = (
+        max_messages,
+        expires=None,
+        max_bytes=None,
+        group=None,
+        min_pending=None,
+        min_ack_pending=None,
+        priority=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn batch(
+        &self,
+        py: Python,
+        max_messages: usize,
+        expires: Option<Duration>,
+        max_bytes: Option<usize>,
+        group: Option<String>,
+        min_pending: Option<usize>,
+        min_ack_pending: Option<usize>,
+        priority: Option<usize>
+    ) -> anyhow::Result<Vec<JetStreamMessage>> {
+        let fetch = PullConsumerFetch{
+            max_messages,
+            max_bytes,
+            expires,
+            no_wait: false,
+            priority_group: PullConsumerPriorityGroup{group, min_pending, min_ack_pending},
+            priority,
+            pin_id: None
+        };
+        self.run_fetch(py, fetch)
+    }
+
+    fn unpin(&self, py: Python, group: String) -> anyhow::Result<()> {
+        let context = self.context.clone();
+        let info = self.consumer.cached_info();
+        let stream = info.stream_name.clone();
+        let consumer = info.name.clone();
+
+        self.pin_ids.lock().unwrap_or_else(PoisonError::into_inner).remove(&group);
+        self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::jetstream::unpin_consumer(context, stream, consumer, group).await
+        })?
+    }
+
+    fn info(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        let name = self.consumer.cached_info().name.clone();
+        send_stream_command(py, &self.stream_task_caller, JetStreamStreamCmd::ConsumerInfo { name })
+    }
+
+    fn pause(&self, py: Python, until: &Bound<PyAny>) -> anyhow::Result<Py<PyAny>> {
+        let name = self.consumer.cached_info().name.clone();
+        let until = converters::offset_datetime::py_to_offset_datetime(until)?;
+        send_stream_command(py, &self.stream_task_caller, JetStreamStreamCmd::PauseConsumer { name, until })
+    }
+
+    fn resume(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        let name = self.consumer.cached_info().name.clone();
+        send_stream_command(py, &self.stream_task_caller, JetStreamStreamCmd::ResumeConsumer { name })
+    }
+}
+
+impl JetStreamPullConsumer {
+    fn run_fetch(
+        &self,
+        py: Python,
+        mut fetch: PullConsumerFetch
+    ) -> anyhow::Result<Vec<JetStreamMessage>> {
+        let group = fetch.priority_group.group.clone();
+        if let Some(group) = group.as_ref() {
+            fetch.pin_id = self.pin_ids.lock().unwrap_or_else(PoisonError::into_inner).get(group).cloned();
+        }
+
+        let (context, consumer) = (self.context.clone(), self.consumer.clone());
+        let batch = self.task_spawner.spawn_blocking(py, async move {
+            crate::cmds::jetstream::pull_consumer_fetch(context, consumer, fetch).await
+        })??;
+
+        if let Some(group) = group {
+            let mut pin_ids = self.pin_ids.lock().unwrap_or_else(PoisonError::into_inner);
+            match batch.pin_id {
+                Some(pin_id) => pin_ids.insert(group, pin_id),
+                None => pin_ids.remove(&group),
+            };
+        }
+        Ok(batch.messages
+            .into_iter()
+            .map(|message| JetStreamMessage{
+                message,
+                task_spawner: self.task_spawner.make_clone(py)
+            })
+            .collect())
+    }
+}
+
+#[pyclass]
+struct JetStreamOrderedConsumer {
+    task_spawner: crate::task::TaskSpawner,
+    consumer: async_nats::jetstream::consumer::OrderedPullConsumer
+}
+
+#[pymethods]
+impl JetStreamOrderedConsumer {
+    fn make_receiver(&self, py: Python) -> anyhow::Result<JetStreamConsumerMessages> {
         let (task_caller, queue) =
             crate::task::TaskCaller::new(self.task_spawner.read_eventfd.clone_ref(py));
 
@@ -70,27 +547,82 @@ impl JetStreamPullConsumer {
         })??;
 
         self.task_spawner.spawn(async move {
-            crate::cmds::jetstream::pull_consumer_messages_loop(stream, queue)
+            crate::cmds::jetstream::consumer_messages_loop(stream, queue)
                 .await
         });
 
-        Ok(JetStreamPullConsumerMessages{task_caller})
+        Ok(JetStreamConsumerMessages{
+            task_caller,
+            task_spawner: self.task_spawner.make_clone(py)
+        })
     }
 }
 
 #[pyclass]
-struct JetStreamPullConsumerMessages {
-    task_caller: crate::task::TaskCaller<(), crate::cmds::jetstream::PullConsumerMessagesResult>,
+struct JetStreamConsumerMessages {
+    task_caller: crate::task::TaskCaller<Option<Duration>, crate::cmds::jetstream::ConsumerMessagesResult>,
+    task_spawner: crate::task::TaskSpawner
 }
 
 #[pymethods]
-impl JetStreamPullConsumerMessages {
-    fn recv_msg(&mut self, py: Python) -> anyhow::Result<Option<Message>> {
-        let result = self.task_caller.req_response(py, ())?;
-        let Some(result) = result? else {
+impl JetStreamConsumerMessages {
+    #[pyo3(signature = (timeout=None))]
+    fn recv_msg(
+        &mut self,
+        py: Python,
+        timeout: Option<Duration>
+    ) -> anyhow::Result<Option<JetStreamMessage>> {
+        let result = self.task_caller.req_response(py, timeout)?;
+        let Some(message) = result.map_err(map_messages_error)? else {
             return Ok(None);
         };
 
-        Ok(Some(Message::new(result.message)))
+        Ok(Some(JetStreamMessage{message, task_spawner: self.task_spawner.make_clone(py)}))
+    }
+}
+
+#[pyclass]
+struct JetStreamPushConsumer {
+    task_spawner: crate::task::TaskSpawner,
+    stream_task_caller: TaskCaller<JetStreamStreamCmd, JetStreamStreamCmdResponse>,
+    consumer: async_nats::jetstream::consumer::PushConsumer
+}
+
+#[pymethods]
+impl JetStreamPushConsumer {
+    fn make_receiver(&self, py: Python) -> anyhow::Result<JetStreamConsumerMessages> {
+        let (task_caller, queue) =
+            crate::task::TaskCaller::new(self.task_spawner.read_eventfd.clone_ref(py));
+
+        let consumer = self.consumer.clone();
+        let stream = self.task_spawner.spawn_blocking(py, async move {
+            consumer.messages().await
+        })??;
+
+        self.task_spawner.spawn(async move {
+            crate::cmds::jetstream::consumer_messages_loop(stream, queue)
+                .await
+        });
+
+        Ok(JetStreamConsumerMessages{
+            task_caller,
+            task_spawner: self.task_spawner.make_clone(py)
+        })
+    }
+
+    fn info(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        let name = self.consumer.cached_info().name.clone();
+        send_stream_command(py, &self.stream_task_caller, JetStreamStreamCmd::ConsumerInfo { name })
+    }
+
+    fn pause(&self, py: Python, until: &Bound<PyAny>) -> anyhow::Result<Py<PyAny>> {
+        let name = self.consumer.cached_info().name.clone();
+        let until = converters::offset_datetime::py_to_offset_datetime(until)?;
+        send_stream_command(py, &self.stream_task_caller, JetStreamStreamCmd::PauseConsumer { name, until })
+    }
+
+    fn resume(&self, py: Python) -> anyhow::Result<Py<PyAny>> {
+        let name = self.consumer.cached_info().name.clone();
+        send_stream_command(py, &self.stream_task_caller, JetStreamStreamCmd::ResumeConsumer { name })
     }
-}
\ No newline at end of file
+}
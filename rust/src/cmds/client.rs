@@ -7,7 +7,7 @@ pub(crate) enum ClientCmd {
     Flush,
     Request(ClientCmdRequest),
     NewInbox,
-    CreateJetStream,
+    CreateJetStream { max_ack_inflight: Option<usize> },
 }
 
 pub(crate) struct ClientCmdPublish {
@@ -50,7 +50,9 @@ pub(crate) async fn main_loop(
         }
         ClientCmd::Request(request) => run_request(&client, request).await,
         ClientCmd::NewInbox => run_new_inbox(&client).await,
-        ClientCmd::CreateJetStream => run_create_jetstream(&client).await,
+        ClientCmd::CreateJetStream { max_ack_inflight } => {
+            run_create_jetstream(&client, max_ack_inflight).await
+        }
     });
 }
 
@@ -128,8 +130,17 @@ async fn run_queue_subscribe(
     Ok(ClientCmdResponse::Subscribe(subscriber))
 }
 
-async fn run_create_jetstream(client: &async_nats::Client
+async fn run_create_jetstream(
+    client: &async_nats::Client,
+    max_ack_inflight: Option<usize>,
 ) -> anyhow::Result<ClientCmdResponse> {
-    let jetstream = async_nats::jetstream::new(client.clone());
+    let jetstream = match max_ack_inflight {
+        // Publishing waits for a free slot once the window is full.
+        Some(max_ack_inflight) => async_nats::jetstream::ContextBuilder::new()
+            .max_ack_inflight(max_ack_inflight)
+            .backpressure_on_inflight(true)
+            .build(client.clone()),
+        None => async_nats::jetstream::new(client.clone()),
+    };
     Ok(ClientCmdResponse::JetStream(jetstream))
 }
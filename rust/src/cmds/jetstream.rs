@@ -1,26 +1,68 @@
 use std::time::Duration;
+use tokio::time::timeout as tokio_timeout;
 use tokio_stream::StreamExt;
 use crate::task::TaskQueueReceiver;
 
+pub(crate) const NATS_SCHEDULE: &str = "Nats-Schedule";
+pub(crate) const NATS_SCHEDULE_TARGET: &str = "Nats-Schedule-Target";
+const NATS_INCR: &str = "Nats-Incr";
+const NATS_BATCH_ID: &str = "Nats-Batch-Id";
+const NATS_BATCH_SEQUENCE: &str = "Nats-Batch-Sequence";
+const NATS_BATCH_COMMIT: &str = "Nats-Batch-Commit";
+/// Default limit of the server for messages in one atomic batch.
+pub(crate) const MAX_BATCH_SIZE: usize = 1000;
+const NATS_PIN_ID: &str = "Nats-Pin-Id";
+
 pub(crate) enum JetStreamCmd {
     Publish {
         subject: String,
-        payload: bytes::Bytes,
-        headers: Option<async_nats::HeaderMap>,
+        publish: async_nats::jetstream::message::PublishMessage,
     },
     GetOrCreateStream {
         config: async_nats::jetstream::stream::Config,
     },
     DeleteStream { stream: String },
-    SetTimeout {timeout: Duration }
+    SetTimeout {timeout: Duration },
+    StreamNames { subject_filter: Option<String> },
+    Streams,
+    CreateStream {
+        config: async_nats::jetstream::stream::Config,
+    },
+    UpdateStream {
+        config: async_nats::jetstream::stream::Config,
+    },
+    GetStream { stream: String },
+    CounterAdd { subject: String, delta: String },
+    CounterGet { subject: String },
+    PublishBatch {
+        messages: Vec<(String, bytes::Bytes, Option<async_nats::HeaderMap>)>,
+    },
+    CreateKeyValue {
+        config: async_nats::jetstream::kv::Config,
+    },
+    KeyValue { bucket: String },
+    DeleteKeyValue { bucket: String },
 }
 
 #[derive(Debug)]
 pub(crate) enum JetStreamCmdResponse {
     NoResponse,
     PublishAck{future: async_nats::jetstream::context::PublishAckFuture},
-    Stream{stream: async_nats::jetstream::stream::Stream},
+    Stream{
+        stream: async_nats::jetstream::stream::Stream,
+        context: async_nats::jetstream::Context,
+    },
     DeleteStatus{success: bool},
+    StreamNames{
+        context: async_nats::jetstream::Context,
+        subject_filter: Option<String>,
+    },
+    Streams{context: async_nats::jetstream::Context},
+    CounterValue{value: Option<String>},
+    KeyValue{
+        store: async_nats::jetstream::kv::Store,
+        context: async_nats::jetstream::Context,
+    },
 }
 
 pub(crate) async fn main_loop(
@@ -28,34 +70,116 @@ pub(crate) async fn main_loop(
     mut task_receiver: TaskQueueReceiver<JetStreamCmd, JetStreamCmdResponse>,
 ) {
     run_task_loop!(task_receiver, {
-        JetStreamCmd::Publish{subject, payload, headers} =>
-            run_publish(&jetstream, subject, payload, headers).await,
+        JetStreamCmd::Publish{subject, publish} =>
+            run_publish(&jetstream, subject, publish).await,
         JetStreamCmd::GetOrCreateStream { config } =>
             run_get_or_create_stream(&jetstream, config).await,
         JetStreamCmd::DeleteStream { stream } =>
             run_delete_stream(&jetstream, stream).await,
         JetStreamCmd::SetTimeout { timeout } =>
-            run_set_timeout(&mut jetstream, timeout).await
+            run_set_timeout(&mut jetstream, timeout).await,
+        JetStreamCmd::StreamNames { subject_filter } =>
+            Ok(JetStreamCmdResponse::StreamNames{context: jetstream.clone(), subject_filter}),
+        JetStreamCmd::Streams =>
+            Ok(JetStreamCmdResponse::Streams{context: jetstream.clone()}),
+        JetStreamCmd::CreateStream { config } =>
+            run_create_stream(&jetstream, config).await,
+        JetStreamCmd::UpdateStream { config } =>
+            run_update_stream(&jetstream, config).await,
+        JetStreamCmd::GetStream { stream } =>
+            run_get_stream(&jetstream, stream).await,
+        JetStreamCmd::CounterAdd { subject, delta } =>
+            run_counter_add(&jetstream, subject, delta).await,
+        JetStreamCmd::CounterGet { subject } =>
+            run_counter_get(&jetstream, subject).await,
+        JetStreamCmd::PublishBatch { messages } =>
+            run_publish_batch(&jetstream, messages).await,
+        JetStreamCmd::CreateKeyValue { config } =>
+            run_create_key_value(&jetstream, config).await,
+        JetStreamCmd::KeyValue { bucket } =>
+            run_key_value(&jetstream, bucket).await,
+        JetStreamCmd::DeleteKeyValue { bucket } =>
+            run_delete_key_value(&jetstream, bucket).await
     });
 }
 
-async fn run_publish(
+/// Sends the messages as one atomic batch. Only the commit message is
+/// acknowledged, the server stores either all messages or none.
+async fn run_publish_batch(
     jetstream: &async_nats::jetstream::Context,
-    subject: String,
-    payload: bytes::Bytes,
-    headers: Option<async_nats::HeaderMap>
+    messages: Vec<(String, bytes::Bytes, Option<async_nats::HeaderMap>)>,
 ) -> Result<JetStreamCmdResponse, anyhow::Error> {
-    let result = match headers {
-        Some(headers) => {
-            jetstream
-                .publish_with_headers(subject, headers, payload)
-                .await?
+    let client = jetstream.client();
+    let batch_id = nuid::next().to_string();
+    let count = messages.len();
+
+    for (index, (subject, payload, headers)) in messages.into_iter().enumerate() {
+        let mut headers = headers.unwrap_or_default();
+        headers.insert(NATS_BATCH_ID, batch_id.as_str());
+        headers.insert(NATS_BATCH_SEQUENCE, (index + 1).to_string());
+
+        if index + 1 < count {
+            client.publish_with_headers(subject, headers, payload).await?;
+            continue;
         }
-        None => {
-            jetstream.publish(subject, payload).await?
+
+        headers.insert(NATS_BATCH_COMMIT, "1");
+        let publish = async_nats::jetstream::message::PublishMessage::build()
+            .payload(payload)
+            .headers(headers);
+        let future = jetstream.send_publish(subject, publish).await?;
+        return Ok(JetStreamCmdResponse::PublishAck{future});
+    }
+
+    anyhow::bail!("A batch needs at least one message")
+}
+
+/// Counter values are decimal strings, they may exceed 64 bits.
+async fn run_counter_add(
+    jetstream: &async_nats::jetstream::Context,
+    subject: String,
+    delta: String,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let publish = async_nats::jetstream::message::PublishMessage::build()
+        .header(NATS_INCR, delta);
+    let ack = jetstream.send_publish(subject, publish).await?.await?;
+
+    let Some(value) = ack.value else {
+        anyhow::bail!("No counter value in the ack, is allow_message_counter set on the stream?");
+    };
+    Ok(JetStreamCmdResponse::CounterValue{value: Some(value)})
+}
+
+async fn run_counter_get(
+    jetstream: &async_nats::jetstream::Context,
+    subject: String,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let stream = jetstream.stream_by_subject(&subject).await?;
+    let stream = jetstream.get_stream_no_info(stream).await?;
+
+    let message = match stream.get_last_raw_message_by_subject(&subject).await {
+        Ok(message) => message,
+        Err(err) => match err.kind() {
+            async_nats::jetstream::stream::LastRawMessageErrorKind::NoMessageFound => {
+                return Ok(JetStreamCmdResponse::CounterValue{value: None});
+            },
+            _ => return Err(err.into()),
         }
     };
 
+    let payload: serde_json::Value = serde_json::from_slice(&message.payload)?;
+    let Some(value) = payload["val"].as_str() else {
+        anyhow::bail!("Message on {subject} is not a counter");
+    };
+    Ok(JetStreamCmdResponse::CounterValue{value: Some(value.to_string())})
+}
+
+async fn run_publish(
+    jetstream: &async_nats::jetstream::Context,
+    subject: String,
+    publish: async_nats::jetstream::message::PublishMessage,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let result = jetstream.send_publish(subject, publish).await?;
     Ok(JetStreamCmdResponse::PublishAck{future: result})
 }
 
@@ -64,7 +188,58 @@ async fn run_get_or_create_stream(
     config: async_nats::jetstream::stream::Config,
 ) -> Result<JetStreamCmdResponse, anyhow::Error> {
     let stream = jetstream.get_or_create_stream(config).await?;
-    Ok(JetStreamCmdResponse::Stream{stream})
+    Ok(JetStreamCmdResponse::Stream{stream, context: jetstream.clone()})
+}
+
+/// The server rejects a stream name in use with `STREAM_NAME_EXIST`, mapped
+/// in `map_stream_error`. A create with the identical config succeeds.
+async fn run_create_stream(
+    jetstream: &async_nats::jetstream::Context,
+    config: async_nats::jetstream::stream::Config,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let stream = jetstream.create_stream(config).await?;
+    Ok(JetStreamCmdResponse::Stream{stream, context: jetstream.clone()})
+}
+
+async fn run_update_stream(
+    jetstream: &async_nats::jetstream::Context,
+    config: async_nats::jetstream::stream::Config,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let info = jetstream.update_stream(&config).await?;
+    let stream = jetstream.get_stream(&info.config.name).await?;
+    Ok(JetStreamCmdResponse::Stream{stream, context: jetstream.clone()})
+}
+
+async fn run_get_stream(
+    jetstream: &async_nats::jetstream::Context,
+    stream: String,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let stream = jetstream.get_stream(&stream).await?;
+    Ok(JetStreamCmdResponse::Stream{stream, context: jetstream.clone()})
+}
+
+async fn run_create_key_value(
+    jetstream: &async_nats::jetstream::Context,
+    config: async_nats::jetstream::kv::Config,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let store = jetstream.create_key_value(config).await?;
+    Ok(JetStreamCmdResponse::KeyValue{store, context: jetstream.clone()})
+}
+
+async fn run_key_value(
+    jetstream: &async_nats::jetstream::Context,
+    bucket: String,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let store = jetstream.get_key_value(bucket).await?;
+    Ok(JetStreamCmdResponse::KeyValue{store, context: jetstream.clone()})
+}
+
+async fn run_delete_key_value(
+    jetstream: &async_nats::jetstream::Context,
+    bucket: String,
+) -> Result<JetStreamCmdResponse, anyhow::Error> {
+    let status = jetstream.delete_key_value(bucket).await?;
+    Ok(JetStreamCmdResponse::DeleteStatus{success: status.success})
 }
 
 async fn run_delete_stream(
@@ -88,19 +263,71 @@ pub enum JetStreamStreamCmd{
     GetOrCreatePullConsumer{
         name: String,
         config: async_nats::jetstream::consumer::pull::Config
-    }
+    },
+    CreateOrderedConsumer{
+        config: async_nats::jetstream::consumer::pull::OrderedConfig
+    },
+    GetPushConsumer{name: String},
+    GetOrCreatePushConsumer{
+        name: String,
+        config: async_nats::jetstream::consumer::push::Config
+    },
+    ConsumerInfo{name: String},
+    DeleteConsumer{name: String},
+    PauseConsumer{name: String, until: time::OffsetDateTime},
+    ResumeConsumer{name: String},
+    ConsumerNames,
+    Consumers,
+    Info{subjects_filter: Option<String>},
+    Purge{
+        filter: Option<String>,
+        keep: Option<u64>,
+        sequence: Option<u64>,
+    },
+    GetMessage{sequence: u64},
+    GetLastMessage{subject: String},
+    DeleteMessage{sequence: u64, erase: bool},
+    DirectGet{sequence: u64},
+    DirectGetLastForSubject{subject: String},
+    DirectGetNextForSubject{subject: String, sequence: Option<u64>},
+    DirectGetMultiLast{
+        subjects: Vec<String>,
+        up_to_sequence: Option<u64>,
+        up_to_time: Option<time::OffsetDateTime>,
+    },
+    Schedules{subject_filter: String},
 }
 
 #[derive(Debug)]
 pub enum JetStreamStreamCmdResponse {
     PullConsumer(async_nats::jetstream::consumer::Consumer<
         async_nats::jetstream::consumer::pull::Config>
-    )
+    ),
+    OrderedConsumer(async_nats::jetstream::consumer::OrderedPullConsumer),
+    PushConsumer(async_nats::jetstream::consumer::PushConsumer),
+    ConsumerInfo(async_nats::jetstream::consumer::Info),
+    DeleteStatus{success: bool},
+    PauseStatus{
+        paused: bool,
+        pause_until: time::OffsetDateTime,
+        pause_remaining: Option<Duration>,
+    },
+    ConsumerNames(async_nats::jetstream::stream::Stream),
+    Consumers(async_nats::jetstream::stream::Stream),
+    Info{
+        info: Box<async_nats::jetstream::stream::Info>,
+        subjects: Option<std::collections::HashMap<String, usize>>,
+    },
+    PurgeStatus{purged: u64},
+    StreamMessage(Box<async_nats::jetstream::message::StreamMessage>),
+    StreamMessages(Vec<async_nats::jetstream::message::StreamMessage>),
+    Schedules(Vec<async_nats::jetstream::message::StreamMessage>),
 }
 
 
 pub async fn stream_loop(
     stream: async_nats::jetstream::stream::Stream,
+    context: async_nats::jetstream::Context,
     mut task_receiver: TaskQueueReceiver<JetStreamStreamCmd, JetStreamStreamCmdResponse>
 ) {
     run_task_loop!(task_receiver, {
@@ -116,21 +343,767 @@ pub async fn stream_loop(
                 .await
                 .map_err(|err| anyhow::anyhow!("{err}"))
                 .map(|ob| JetStreamStreamCmdResponse::PullConsumer(ob))
+        },
+        JetStreamStreamCmd::CreateOrderedConsumer { config } => {
+            stream.create_consumer(config)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(JetStreamStreamCmdResponse::OrderedConsumer)
+        },
+        JetStreamStreamCmd::GetPushConsumer { name } => {
+            stream
+                .get_consumer::<async_nats::jetstream::consumer::push::Config>(&name)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(JetStreamStreamCmdResponse::PushConsumer)
+        },
+        JetStreamStreamCmd::GetOrCreatePushConsumer { name, config } => {
+            stream.get_or_create_consumer(&name, config)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(JetStreamStreamCmdResponse::PushConsumer)
+        },
+        JetStreamStreamCmd::ConsumerInfo { name } => {
+            stream.consumer_info(&name)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(JetStreamStreamCmdResponse::ConsumerInfo)
+        },
+        JetStreamStreamCmd::DeleteConsumer { name } => {
+            stream.delete_consumer(&name)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|status| JetStreamStreamCmdResponse::DeleteStatus{success: status.success})
+        },
+        JetStreamStreamCmd::PauseConsumer { name, until } => {
+            stream.pause_consumer(&name, until)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|response| JetStreamStreamCmdResponse::PauseStatus{
+                    paused: response.paused,
+                    pause_until: response.pause_until,
+                    pause_remaining: response.pause_remaining,
+                })
+        },
+        JetStreamStreamCmd::ResumeConsumer { name } => {
+            stream.resume_consumer(&name)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|response| JetStreamStreamCmdResponse::PauseStatus{
+                    paused: response.paused,
+                    pause_until: response.pause_until,
+                    pause_remaining: response.pause_remaining,
+                })
+        },
+        JetStreamStreamCmd::ConsumerNames =>
+            Ok(JetStreamStreamCmdResponse::ConsumerNames(stream.clone())),
+        JetStreamStreamCmd::Consumers =>
+            Ok(JetStreamStreamCmdResponse::Consumers(stream.clone())),
+        JetStreamStreamCmd::Info { subjects_filter } =>
+            run_stream_info(&stream, subjects_filter).await,
+        JetStreamStreamCmd::Purge { filter, keep, sequence } =>
+            run_stream_purge(&stream, filter, keep, sequence).await,
+        JetStreamStreamCmd::GetMessage { sequence } => {
+            stream.get_raw_message(sequence)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|message| JetStreamStreamCmdResponse::StreamMessage(Box::new(message)))
+        },
+        JetStreamStreamCmd::GetLastMessage { subject } => {
+            stream.get_last_raw_message_by_subject(&subject)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|message| JetStreamStreamCmdResponse::StreamMessage(Box::new(message)))
+        },
+        JetStreamStreamCmd::DeleteMessage { sequence, erase } =>
+            run_delete_message(&context, &stream.cached_info().config.name, sequence, erase).await,
+        JetStreamStreamCmd::DirectGet { sequence } => {
+            stream.direct_get(sequence)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|message| JetStreamStreamCmdResponse::StreamMessage(Box::new(message)))
+        },
+        JetStreamStreamCmd::DirectGetLastForSubject { subject } => {
+            stream.direct_get_last_for_subject(subject)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|message| JetStreamStreamCmdResponse::StreamMessage(Box::new(message)))
+        },
+        JetStreamStreamCmd::DirectGetNextForSubject { subject, sequence } => {
+            stream.direct_get_next_for_subject(subject, sequence)
+                .await
+                .map_err(|err| anyhow::anyhow!("{err}"))
+                .map(|message| JetStreamStreamCmdResponse::StreamMessage(Box::new(message)))
+        },
+        JetStreamStreamCmd::DirectGetMultiLast { subjects, up_to_sequence, up_to_time } =>
+            run_direct_get_multi_last(
+                &context, &stream.cached_info().config.name, subjects, up_to_sequence, up_to_time
+            ).await,
+        JetStreamStreamCmd::Schedules { subject_filter } =>
+            run_schedules(&stream, subject_filter).await
+    });
+}
+
+async fn run_schedules(
+    stream: &async_nats::jetstream::stream::Stream,
+    subject_filter: String,
+) -> anyhow::Result<JetStreamStreamCmdResponse> {
+    let mut info = stream
+        .info_with_subjects(subject_filter)
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    let mut subjects = Vec::new();
+    while let Some(subject) = info.next().await {
+        let (subject, _) = subject.map_err(|err| anyhow::anyhow!("{err}"))?;
+        subjects.push(subject);
+    }
+
+    // A schedule is the last message on its subject carrying the schedule header.
+    let mut schedules = Vec::new();
+    for subject in subjects {
+        let message = stream
+            .get_last_raw_message_by_subject(&subject)
+            .await
+            .map_err(|err| anyhow::anyhow!("{err}"))?;
+        if message.headers.get(NATS_SCHEDULE).is_some() {
+            schedules.push(message);
         }
+    }
+
+    Ok(JetStreamStreamCmdResponse::Schedules(schedules))
+}
+
+async fn run_direct_get_multi_last(
+    context: &async_nats::jetstream::Context,
+    stream: &str,
+    subjects: Vec<String>,
+    up_to_sequence: Option<u64>,
+    up_to_time: Option<time::OffsetDateTime>,
+) -> anyhow::Result<JetStreamStreamCmdResponse> {
+    // async_nats has no batched direct get. The server streams one message
+    // per subject to the reply inbox and ends the batch with a 204 status.
+    let client = context.client();
+    let timeout = client.timeout().unwrap_or(Duration::from_secs(10));
+
+    let mut payload = serde_json::json!({ "multi_last": subjects });
+    if let Some(up_to_sequence) = up_to_sequence {
+        payload["up_to_seq"] = up_to_sequence.into();
+    }
+    if let Some(up_to_time) = up_to_time {
+        payload["up_to_time"] =
+            up_to_time.format(&time::format_description::well_known::Rfc3339)?.into();
+    }
+
+    // `send_request` puts the API prefix of the context in front of the subject.
+    let subject = format!("DIRECT.GET.{stream}");
+    let inbox = client.new_inbox();
+    let mut subscriber = client.subscribe(inbox.clone()).await?;
+    let request = async_nats::Request::new()
+        .payload(serde_json::to_vec(&payload)?.into())
+        .inbox(inbox);
+    context.send_request(subject, request).await?;
+
+    let mut messages = Vec::new();
+    loop {
+        let Some(message) = tokio_timeout(timeout, subscriber.next()).await? else {
+            anyhow::bail!("direct get subscription closed");
+        };
+
+        match message.status.map(|status| status.as_u16()) {
+            None => messages.push(message.try_into()?),
+            Some(204) | Some(404) => break,
+            Some(status) => anyhow::bail!(
+                "direct get failed: {status} {}",
+                message.description.unwrap_or_default()
+            ),
+        }
+    }
+
+    Ok(JetStreamStreamCmdResponse::StreamMessages(messages))
+}
+
+async fn run_delete_message(
+    context: &async_nats::jetstream::Context,
+    stream: &str,
+    sequence: u64,
+    erase: bool,
+) -> anyhow::Result<JetStreamStreamCmdResponse> {
+    // Stream::delete_message always erases, the fast delete needs no_erase.
+    let subject = format!("STREAM.MSG.DELETE.{stream}");
+    let payload = serde_json::json!({ "seq": sequence, "no_erase": !erase });
+
+    let response: async_nats::jetstream::response::Response<serde_json::Value> =
+        context.request(subject, &payload).await?;
+
+    match response {
+        async_nats::jetstream::response::Response::Ok(status) => {
+            let success = status["success"].as_bool().unwrap_or_default();
+            Ok(JetStreamStreamCmdResponse::DeleteStatus{success})
+        },
+        async_nats::jetstream::response::Response::Err { error } => Err(error.into()),
+    }
+}
+
+async fn run_stream_purge(
+    stream: &async_nats::jetstream::stream::Stream,
+    filter: Option<String>,
+    keep: Option<u64>,
+    sequence: Option<u64>,
+) -> anyhow::Result<JetStreamStreamCmdResponse> {
+    let mut builder = stream.purge();
+    if let Some(filter) = filter {
+        builder = builder.filter(filter);
+    }
+
+    // keep and sequence are exclusive, the builder encodes this in its type.
+    let response = match (keep, sequence) {
+        (None, None) => builder.await,
+        (Some(keep), None) => builder.keep(keep).await,
+        (None, Some(sequence)) => builder.sequence(sequence).await,
+        (Some(_), Some(_)) => anyhow::bail!("keep and sequence cannot be used together"),
+    };
+
+    let response = response.map_err(|err| anyhow::anyhow!("{err}"))?;
+    Ok(JetStreamStreamCmdResponse::PurgeStatus{purged: response.purged})
+}
+
+async fn run_stream_info(
+    stream: &async_nats::jetstream::stream::Stream,
+    subjects_filter: Option<String>,
+) -> anyhow::Result<JetStreamStreamCmdResponse> {
+    let Some(subjects_filter) = subjects_filter else {
+        let info = stream.get_info().await.map_err(|err| anyhow::anyhow!("{err}"))?;
+        return Ok(JetStreamStreamCmdResponse::Info{info: Box::new(info), subjects: None});
+    };
+
+    let mut info = stream
+        .info_with_subjects(subjects_filter)
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    // The per-subject counts are paged, collect all of them.
+    let mut subjects = std::collections::HashMap::new();
+    while let Some(subject) = info.next().await {
+        let (subject, count) = subject.map_err(|err| anyhow::anyhow!("{err}"))?;
+        subjects.insert(subject, count);
+    }
+
+    Ok(JetStreamStreamCmdResponse::Info{info: Box::new(info.info), subjects: Some(subjects)})
+}
+
+pub(crate) enum JetStreamListItem {
+    Name(String),
+    StreamInfo(Box<async_nats::jetstream::stream::Info>),
+    ConsumerInfo(Box<async_nats::jetstream::consumer::Info>),
+}
+
+impl From<String> for JetStreamListItem {
+    fn from(name: String) -> Self {
+        JetStreamListItem::Name(name)
+    }
+}
+
+impl From<async_nats::jetstream::stream::Info> for JetStreamListItem {
+    fn from(info: async_nats::jetstream::stream::Info) -> Self {
+        JetStreamListItem::StreamInfo(Box::new(info))
+    }
+}
+
+impl From<async_nats::jetstream::consumer::Info> for JetStreamListItem {
+    fn from(info: async_nats::jetstream::consumer::Info) -> Self {
+        JetStreamListItem::ConsumerInfo(Box::new(info))
+    }
+}
+
+pub(crate) type JetStreamListResult = Option<JetStreamListItem>;
+
+/// Drives one of the async_nats listers, fetching the next page only
+/// when Python asks for an item past the current one.
+pub(crate) async fn list_loop<S, T, E>(
+    mut stream: S,
+    mut task_receiver: TaskQueueReceiver<(), JetStreamListResult>,
+) where
+    S: tokio_stream::Stream<Item = Result<T, E>> + Unpin,
+    T: Into<JetStreamListItem>,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    run_task_loop!(task_receiver, {
+        () => match stream.next().await.transpose() {
+            Ok(item) => Ok(item.map(Into::into)),
+            Err(err) => Err(err.into()),
+        }
+    });
+}
+
+/// `Context::stream_names` cannot filter by subject, so the pages are
+/// requested here instead.
+pub(crate) async fn stream_names_loop(
+    context: async_nats::jetstream::Context,
+    subject_filter: Option<String>,
+    mut task_receiver: TaskQueueReceiver<(), JetStreamListResult>,
+) {
+    let mut names = std::collections::VecDeque::new();
+    let mut offset = 0;
+    let mut done = false;
+
+    run_task_loop!(task_receiver, {
+        () => next_stream_name(
+            &context, subject_filter.as_deref(), &mut names, &mut offset, &mut done
+        ).await
+    });
+}
+
+async fn next_stream_name(
+    context: &async_nats::jetstream::Context,
+    subject_filter: Option<&str>,
+    names: &mut std::collections::VecDeque<String>,
+    offset: &mut usize,
+    done: &mut bool,
+) -> anyhow::Result<JetStreamListResult> {
+    if names.is_empty() && !*done {
+        let mut payload = serde_json::json!({ "offset": *offset });
+        if let Some(subject) = subject_filter {
+            payload["subject"] = subject.into();
+        }
+
+        let response: async_nats::jetstream::response::Response<serde_json::Value> =
+            context.request("STREAM.NAMES", &payload).await?;
+        let page = match response {
+            async_nats::jetstream::response::Response::Ok(page) => page,
+            async_nats::jetstream::response::Response::Err { error } => return Err(error.into()),
+        };
+
+        let total = page["total"].as_u64().unwrap_or_default() as usize;
+        if let Some(streams) = page["streams"].as_array() {
+            names.extend(streams.iter().filter_map(|name| name.as_str().map(String::from)));
+        }
+
+        *offset += names.len();
+        *done = names.is_empty() || *offset >= total;
+    }
+
+    Ok(names.pop_front().map(JetStreamListItem::Name))
+}
+
+pub type ConsumerMessagesResult = Option<async_nats::jetstream::Message>;
+
+pub async fn consumer_messages_loop<S, E>(
+    mut stream: S,
+    mut task_receiver: TaskQueueReceiver<Option<Duration>, ConsumerMessagesResult>,
+) where
+    S: tokio_stream::Stream<Item = Result<async_nats::jetstream::Message, E>> + Unpin,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    run_task_loop!(task_receiver, {
+        timeout => run_consumer_next(&mut stream, timeout).await
     });
 }
 
-pub type PullConsumerMessagesResult = Option<async_nats::jetstream::Message>;
+async fn run_consumer_next<S, E>(
+    stream: &mut S,
+    timeout: Option<Duration>,
+) -> anyhow::Result<ConsumerMessagesResult>
+where
+    S: tokio_stream::Stream<Item = Result<async_nats::jetstream::Message, E>> + Unpin,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    // The elapsed timeout is returned as error, None is the end of the stream.
+    let msg = match timeout {
+        Some(timeout) => tokio_timeout(timeout, stream.next()).await?,
+        None => stream.next().await,
+    };
+    Ok(msg.transpose()?)
+}
 
-pub async fn pull_consumer_messages_loop(
-    mut stream: async_nats::jetstream::consumer::pull::Stream,
-    mut task_receiver: TaskQueueReceiver<(), PullConsumerMessagesResult>,
+pub(crate) struct PullConsumerStream {
+    pub(crate) max_messages: Option<usize>,
+    pub(crate) max_bytes: Option<usize>,
+    pub(crate) expires: Option<Duration>,
+    pub(crate) heartbeat: Option<Duration>,
+    pub(crate) threshold_messages: Option<usize>,
+    pub(crate) threshold_bytes: Option<usize>,
+    pub(crate) priority_group: PullConsumerPriorityGroup,
+    pub(crate) priority: Option<usize>,
+}
+
+/// Keeps pull requests open for a receiver. `Consumer::stream` of
+/// async_nats always asks for more at half of a batch and never sends the
+/// pin id of a pinned client group, so the requests are sent here.
+pub(crate) struct PullConsumerMessages {
+    context: async_nats::jetstream::Context,
+    consumer: async_nats::jetstream::consumer::PullConsumer,
+    subject: String,
+    inbox: String,
+    subscriber: async_nats::Subscriber,
+    config: async_nats::jetstream::consumer::pull::BatchConfig,
+    threshold_messages: usize,
+    threshold_bytes: usize,
+    /// Messages and bytes the open pull requests may still deliver.
+    pending_messages: usize,
+    pending_bytes: usize,
+    /// The open pull requests are given up after this, in case the timeout
+    /// status of the server is lost.
+    expired_at: Option<tokio::time::Instant>,
+    /// Last time anything arrived, for the idle heartbeat check.
+    last_activity: tokio::time::Instant,
+    /// Connects of the client seen last, to ask again after a reconnect.
+    connects: u64,
+    pin_id: Option<String>,
+    terminated: bool,
+}
+
+/// How often reconnects are checked for while waiting for messages.
+const RECONNECT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+pub(crate) async fn pull_consumer_stream(
+    context: async_nats::jetstream::Context,
+    consumer: async_nats::jetstream::consumer::PullConsumer,
+    options: PullConsumerStream,
+) -> anyhow::Result<PullConsumerMessages> {
+    // Same defaults as `Consumer::messages`. The server rejects heartbeats
+    // above half of expires.
+    let expires = options.expires.unwrap_or(Duration::from_secs(30));
+    let heartbeat = options.heartbeat.unwrap_or((expires / 2).min(Duration::from_secs(15)));
+
+    let config = async_nats::jetstream::consumer::pull::BatchConfig {
+        batch: options.max_messages.unwrap_or(200),
+        expires: Some(expires),
+        max_bytes: options.max_bytes.unwrap_or_default(),
+        idle_heartbeat: heartbeat,
+        min_pending: options.priority_group.min_pending,
+        min_ack_pending: options.priority_group.min_ack_pending,
+        group: options.priority_group.group,
+        priority: options.priority,
+        ..Default::default()
+    };
+
+    let info = consumer.cached_info();
+    let client = context.client();
+    let inbox = client.new_inbox();
+    let subscriber = client.subscribe(inbox.clone()).await?;
+
+    Ok(PullConsumerMessages {
+        subject: format!("CONSUMER.MSG.NEXT.{}.{}", info.stream_name, info.name),
+        inbox,
+        subscriber,
+        threshold_messages: options.threshold_messages.unwrap_or(config.batch / 2),
+        threshold_bytes: options.threshold_bytes.unwrap_or(config.max_bytes / 2),
+        config,
+        connects: client.statistics().connects.load(std::sync::atomic::Ordering::Relaxed),
+        context,
+        consumer,
+        pending_messages: 0,
+        pending_bytes: 0,
+        expired_at: None,
+        last_activity: tokio::time::Instant::now(),
+        pin_id: None,
+        terminated: false,
+    })
+}
+
+impl PullConsumerMessages {
+    async fn next(
+        &mut self,
+    ) -> Result<Option<async_nats::jetstream::Message>, async_nats::jetstream::consumer::pull::MessagesError> {
+        use async_nats::jetstream::consumer::pull::MessagesError;
+        use async_nats::jetstream::consumer::pull::MessagesErrorKind;
+
+        loop {
+            if self.terminated {
+                return Ok(None);
+            }
+
+            // The server forgets the pull requests of a lost connection, like
+            // async_nats they are asked for again if none is waiting.
+            let connects = self.context.client().statistics().connects.load(std::sync::atomic::Ordering::Relaxed);
+            if connects != self.connects {
+                let info = self.consumer.get_info()
+                    .await
+                    .map_err(|err| MessagesError::with_source(MessagesErrorKind::Pull, err));
+                self.connects = connects;
+                if info?.num_waiting == 0 {
+                    self.pending_messages = 0;
+                    self.pending_bytes = 0;
+                }
+            }
+
+            if self.pending_messages <= self.threshold_messages
+                || (self.config.max_bytes > 0 && self.pending_bytes <= self.threshold_bytes) {
+                self.request().await?;
+            }
+
+            let heartbeat_deadline = (!self.config.idle_heartbeat.is_zero())
+                .then(|| self.last_activity + self.config.idle_heartbeat.saturating_mul(2));
+            let deadline = [self.expired_at, heartbeat_deadline]
+                .into_iter()
+                .flatten()
+                .fold(tokio::time::Instant::now() + RECONNECT_CHECK_INTERVAL, std::cmp::min);
+
+            let message = match tokio::time::timeout_at(deadline, self.subscriber.next()).await {
+                Ok(message) => message,
+                Err(_) => {
+                    let now = tokio::time::Instant::now();
+                    if heartbeat_deadline.is_some_and(|heartbeat| heartbeat <= now) {
+                        self.last_activity = now;
+                        return Err(MessagesError::new(MessagesErrorKind::MissingHeartbeat));
+                    }
+                    if self.expired_at.is_some_and(|expired_at| expired_at <= now) {
+                        self.expired_at = None;
+                        self.pending_messages = 0;
+                        self.pending_bytes = 0;
+                    }
+                    continue;
+                },
+            };
+            let Some(message) = message else {
+                return Ok(None);
+            };
+            self.last_activity = tokio::time::Instant::now();
+
+            match message.status.map(|status| status.as_u16()) {
+                None | Some(200) => {
+                    self.pending_messages = self.pending_messages.saturating_sub(1);
+                    self.pending_bytes = self.pending_bytes.saturating_sub(message.length);
+                    if let Some(id) = message.headers.as_ref().and_then(|headers| headers.get(NATS_PIN_ID)) {
+                        self.pin_id = Some(id.to_string());
+                    }
+                    return Ok(Some(async_nats::jetstream::Message{message, context: self.context.clone()}));
+                },
+                Some(100) => {},
+                Some(408) | Some(409) | Some(423) => {
+                    match message.description.as_deref() {
+                        Some("Consumer Deleted") => {
+                            self.terminated = true;
+                            return Err(MessagesError::new(MessagesErrorKind::ConsumerDeleted));
+                        },
+                        Some("Consumer is push based") => {
+                            self.terminated = true;
+                            return Err(MessagesError::new(MessagesErrorKind::PushBasedConsumer));
+                        },
+                        _ => {},
+                    }
+                    // Another client holds the pin now, the next request asks for a new one.
+                    if message.status.is_some_and(|status| status.as_u16() == 423) {
+                        self.pin_id = None;
+                    }
+
+                    // The request ended, what it did not deliver is no longer pending.
+                    let headers = message.headers.as_ref();
+                    let pending_messages = pending_header(headers, "Nats-Pending-Messages", self.config.batch)?;
+                    let pending_bytes = pending_header(headers, "Nats-Pending-Bytes", self.config.max_bytes)?;
+                    self.pending_messages = self.pending_messages.saturating_sub(pending_messages);
+                    self.pending_bytes = self.pending_bytes.saturating_sub(pending_bytes);
+                },
+                Some(503) => return Err(MessagesError::new(MessagesErrorKind::NoResponders)),
+                Some(status) => return Err(MessagesError::with_source(
+                    MessagesErrorKind::Other,
+                    format!(
+                        "error while processing messages from the stream: {status} {}",
+                        message.description.unwrap_or_default()
+                    ),
+                )),
+            }
+        }
+    }
+
+    /// Asks for another batch.
+    async fn request(&mut self) -> Result<(), async_nats::jetstream::consumer::pull::MessagesError> {
+        use async_nats::jetstream::consumer::pull::MessagesError;
+        use async_nats::jetstream::consumer::pull::MessagesErrorKind;
+
+        let payload = pull_request_payload(&self.config, self.pin_id.as_deref())
+            .map_err(|err| MessagesError::with_source(MessagesErrorKind::Pull, err))?;
+        let request = async_nats::Request::new()
+            .payload(payload)
+            .inbox(self.inbox.clone());
+
+        // Counted before sending, `next` may be cancelled by the timeout of
+        // `recv_msg` while sending. A lost request then expires as usual.
+        self.pending_messages += self.config.batch;
+        self.pending_bytes += self.config.max_bytes;
+        // Requests without expires are kept by the server until fulfilled.
+        self.expired_at = self.config.expires.filter(|expires| !expires.is_zero()).map(|expires| {
+            tokio::time::Instant::now() + expires.saturating_add(Duration::from_secs(5))
+        });
+
+        let result = self.context.send_request(self.subject.clone(), request).await;
+        if let Err(err) = result {
+            self.pending_messages = self.pending_messages.saturating_sub(self.config.batch);
+            self.pending_bytes = self.pending_bytes.saturating_sub(self.config.max_bytes);
+            return Err(MessagesError::with_source(MessagesErrorKind::Pull, err));
+        }
+        Ok(())
+    }
+}
+
+fn pending_header(
+    headers: Option<&async_nats::HeaderMap>,
+    name: &str,
+    default: usize,
+) -> Result<usize, async_nats::jetstream::consumer::pull::MessagesError> {
+    headers
+        .and_then(|headers| headers.get(name))
+        .map_or(Ok(default), |value| value.as_str().parse())
+        .map_err(|err| async_nats::jetstream::consumer::pull::MessagesError::with_source(
+            async_nats::jetstream::consumer::pull::MessagesErrorKind::Other, err
+        ))
+}
+
+/// Body of a pull request, `BatchConfig` has no field for the pin id.
+fn pull_request_payload(
+    config: &async_nats::jetstream::consumer::pull::BatchConfig,
+    pin_id: Option<&str>,
+) -> anyhow::Result<bytes::Bytes> {
+    let mut payload = serde_json::to_value(config)?;
+    if let Some(pin_id) = pin_id {
+        payload["id"] = pin_id.into();
+    }
+    Ok(serde_json::to_vec(&payload)?.into())
+}
+
+pub(crate) async fn pull_messages_loop(
+    mut messages: PullConsumerMessages,
+    mut task_receiver: TaskQueueReceiver<Option<Duration>, ConsumerMessagesResult>,
 ) {
     run_task_loop!(task_receiver, {
-        () => stream
-            .next()
-            .await
-            .transpose()
-            .map_err(|err| err.into())
+        timeout => run_pull_messages_next(&mut messages, timeout).await
     });
 }
+
+async fn run_pull_messages_next(
+    messages: &mut PullConsumerMessages,
+    timeout: Option<Duration>,
+) -> anyhow::Result<ConsumerMessagesResult> {
+    let msg = match timeout {
+        Some(timeout) => tokio_timeout(timeout, messages.next()).await??,
+        None => messages.next().await?,
+    };
+    Ok(msg)
+}
+
+pub(crate) async fn unpin_consumer(
+    context: async_nats::jetstream::Context,
+    stream: String,
+    consumer: String,
+    group: String,
+) -> anyhow::Result<()> {
+    // async_nats has no wrapper for this endpoint yet.
+    let subject = format!("CONSUMER.UNPIN.{stream}.{consumer}");
+    let payload = serde_json::json!({ "group": group });
+
+    let response: async_nats::jetstream::response::Response<serde_json::Value> =
+        context.request(subject, &payload).await?;
+
+    match response {
+        async_nats::jetstream::response::Response::Ok(_) => Ok(()),
+        async_nats::jetstream::response::Response::Err { error } => Err(error.into()),
+    }
+}
+
+pub(crate) struct PullConsumerFetch {
+    pub(crate) max_messages: usize,
+    pub(crate) max_bytes: Option<usize>,
+    pub(crate) expires: Option<Duration>,
+    pub(crate) no_wait: bool,
+    pub(crate) priority_group: PullConsumerPriorityGroup,
+    pub(crate) priority: Option<usize>,
+    /// Pin id the server handed out with the last batch of a pinned group.
+    pub(crate) pin_id: Option<String>,
+}
+
+pub(crate) struct PullConsumerPriorityGroup {
+    pub(crate) group: Option<String>,
+    pub(crate) min_pending: Option<usize>,
+    pub(crate) min_ack_pending: Option<usize>,
+}
+
+pub(crate) struct PullConsumerBatch {
+    pub(crate) messages: Vec<async_nats::jetstream::Message>,
+    /// Pin id to send with the next pull, None once the pin is lost.
+    pub(crate) pin_id: Option<String>,
+}
+
+pub(crate) async fn pull_consumer_fetch(
+    context: async_nats::jetstream::Context,
+    consumer: async_nats::jetstream::consumer::PullConsumer,
+    fetch: PullConsumerFetch,
+) -> anyhow::Result<PullConsumerBatch> {
+    // The fetch and batch builders of async_nats can neither send the pin id
+    // of a pinned client group nor a priority, so the pull request is sent here.
+    let info = consumer.cached_info();
+    let subject = format!("CONSUMER.MSG.NEXT.{}.{}", info.stream_name, info.name);
+
+    // A batch without expires waits for the server as long as it keeps the
+    // request, like `Consumer::batch` does.
+    let expires = match fetch.no_wait {
+        true => fetch.expires,
+        false => Some(fetch.expires.unwrap_or_default()),
+    };
+
+    let config = async_nats::jetstream::consumer::pull::BatchConfig {
+        batch: fetch.max_messages,
+        expires,
+        no_wait: fetch.no_wait,
+        max_bytes: fetch.max_bytes.unwrap_or_default(),
+        min_pending: fetch.priority_group.min_pending,
+        min_ack_pending: fetch.priority_group.min_ack_pending,
+        group: fetch.priority_group.group,
+        priority: fetch.priority,
+        ..Default::default()
+    };
+
+    let client = context.client();
+    let inbox = client.new_inbox();
+    let mut subscriber = client.subscribe(inbox.clone()).await?;
+    let request = async_nats::Request::new()
+        .payload(pull_request_payload(&config, fetch.pin_id.as_deref())?)
+        .inbox(inbox);
+    context.send_request(subject, request).await?;
+
+    // Gives up a bit after the server should have ended the request, in
+    // case its timeout status is lost.
+    let deadline = expires.map(|expires| {
+        tokio::time::Instant::now() + expires.saturating_add(Duration::from_secs(5))
+    });
+
+    let mut pin_id = fetch.pin_id;
+    let mut messages = Vec::new();
+    while messages.len() < fetch.max_messages {
+        let message = match deadline {
+            Some(deadline) => match tokio::time::timeout_at(deadline, subscriber.next()).await {
+                Ok(message) => message,
+                Err(_) => break,
+            },
+            None => subscriber.next().await,
+        };
+        let Some(message) = message else {
+            break;
+        };
+
+        match message.status.map(|status| status.as_u16()) {
+            None | Some(200) => {
+                if let Some(id) = message.headers.as_ref().and_then(|headers| headers.get(NATS_PIN_ID)) {
+                    pin_id = Some(id.to_string());
+                }
+                messages.push(async_nats::jetstream::Message{message, context: context.clone()});
+            },
+            Some(100) => {},
+            Some(404) | Some(408) => break,
+            // Another client holds the pin now, the next pull asks for a new one.
+            Some(423) => {
+                pin_id = None;
+                break;
+            },
+            // Any other status ends the pull request. Messages delivered
+            // before are returned, they would be redelivered otherwise.
+            Some(_) if !messages.is_empty() => break,
+            Some(status) => anyhow::bail!(
+                "error while processing messages from the stream: {status} {}",
+                message.description.unwrap_or_default()
+            ),
+        }
+    }
+
+    Ok(PullConsumerBatch{messages, pin_id})
+}
@@ -0,0 +1,294 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::PoisonError;
+use std::sync::RwLock;
+use std::time::Duration;
+use tokio::time::timeout as tokio_timeout;
+use tokio_stream::StreamExt;
+use crate::cmds::jetstream::JetStreamListResult;
+use crate::cmds::jetstream::list_loop;
+use crate::task::TaskQueueReceiver;
+
+pub(crate) enum KeyValueCmd {
+    Get { key: String },
+    Put { key: String, value: bytes::Bytes },
+    Create { key: String, value: bytes::Bytes },
+    Update { key: String, value: bytes::Bytes, revision: u64 },
+    Delete { key: String, revision: Option<u64> },
+    Purge { key: String, revision: Option<u64> },
+    Entry { key: String, revision: Option<u64> },
+    History { key: String },
+    PurgeDeletes { older_than: Duration },
+    Status,
+}
+
+#[derive(Debug)]
+pub(crate) enum KeyValueCmdResponse {
+    NoResponse,
+    Value(Option<bytes::Bytes>),
+    Revision(u64),
+    Entry(Option<async_nats::jetstream::kv::Entry>),
+    Entries(Vec<async_nats::jetstream::kv::Entry>),
+    Status(Box<async_nats::jetstream::kv::bucket::Status>),
+}
+
+pub(crate) async fn main_loop(
+    store: async_nats::jetstream::kv::Store,
+    idle_timeout: Duration,
+    mut task_receiver: TaskQueueReceiver<KeyValueCmd, KeyValueCmdResponse>,
+) {
+    run_task_loop!(task_receiver, {
+        KeyValueCmd::Get { key } => {
+            store.get(key)
+                .await
+                .map(KeyValueCmdResponse::Value)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::Put { key, value } => {
+            store.put(key, value)
+                .await
+                .map(KeyValueCmdResponse::Revision)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::Create { key, value } => {
+            store.create(key, value)
+                .await
+                .map(KeyValueCmdResponse::Revision)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::Update { key, value, revision } => {
+            store.update(key, value, revision)
+                .await
+                .map(KeyValueCmdResponse::Revision)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::Delete { key, revision } => {
+            store.delete_expect_revision(key, revision)
+                .await
+                .map(|_| KeyValueCmdResponse::NoResponse)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::Purge { key, revision } => {
+            store.purge_expect_revision(key, revision)
+                .await
+                .map(|_| KeyValueCmdResponse::NoResponse)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::Entry { key, revision } => {
+            let entry = match revision {
+                Some(revision) => store.entry_for_revision(key, revision).await,
+                None => store.entry(key).await,
+            };
+            entry
+                .map(KeyValueCmdResponse::Entry)
+                .map_err(Into::into)
+        },
+        KeyValueCmd::History { key } => run_history(&store, key, idle_timeout).await,
+        KeyValueCmd::PurgeDeletes { older_than } =>
+            run_purge_deletes(&store, older_than, idle_timeout).await,
+        KeyValueCmd::Status => {
+            store.status()
+                .await
+                .map(|status| KeyValueCmdResponse::Status(Box::new(status)))
+                .map_err(Into::into)
+        }
+    });
+}
+
+/// The history consumer only ends after the entry whose delta is 0. A key
+/// without messages never gets one, so waiting also ends once nothing
+/// arrived for `idle_timeout`.
+async fn run_history(
+    store: &async_nats::jetstream::kv::Store,
+    key: String,
+    idle_timeout: Duration,
+) -> anyhow::Result<KeyValueCmdResponse> {
+    let mut history = store.history(key).await?;
+    let mut entries = Vec::new();
+    while let Ok(Some(entry)) = tokio_timeout(idle_timeout, history.next()).await {
+        entries.push(entry?);
+    }
+    Ok(KeyValueCmdResponse::Entries(entries))
+}
+
+/// Removes the history of deleted and purged keys. Markers older than
+/// `older_than` are removed as well, newer ones are kept so that watchers
+/// still see the delete.
+async fn run_purge_deletes(
+    store: &async_nats::jetstream::kv::Store,
+    older_than: Duration,
+    idle_timeout: Duration,
+) -> anyhow::Result<KeyValueCmdResponse> {
+    if store.status().await?.values() == 0 {
+        return Ok(KeyValueCmdResponse::NoResponse);
+    }
+
+    // Like `kv_cache_load`, the entry whose delta is 0 never arrives if the
+    // remaining entries are removed before they are delivered.
+    let mut markers = Vec::new();
+    let mut watch = store.watch_with_history(">").await?;
+    while let Ok(Some(entry)) = tokio_timeout(idle_timeout, watch.next()).await {
+        let entry = entry?;
+        let last = entry.delta == 0;
+        if entry.operation != async_nats::jetstream::kv::Operation::Put {
+            markers.push(entry);
+        }
+        if last {
+            break;
+        }
+    }
+
+    let limit = time::OffsetDateTime::now_utc() - older_than;
+    for marker in markers {
+        let keep = if marker.created < limit { 0 } else { 1 };
+        store.stream
+            .purge()
+            .filter(format!("{}{}", store.prefix, marker.key))
+            .keep(keep)
+            .await?;
+    }
+    Ok(KeyValueCmdResponse::NoResponse)
+}
+
+pub(crate) async fn kv_watch(
+    store: async_nats::jetstream::kv::Store,
+    key: Option<String>,
+    history: bool,
+) -> anyhow::Result<async_nats::jetstream::kv::Watch> {
+    let watch = match (key, history) {
+        (Some(key), false) => store.watch(key).await?,
+        (Some(key), true) => store.watch_with_history(key).await?,
+        (None, false) => store.watch_all().await?,
+        (None, true) => store.watch_with_history(">").await?,
+    };
+    Ok(watch)
+}
+
+pub(crate) async fn kv_keys(
+    store: async_nats::jetstream::kv::Store,
+) -> anyhow::Result<async_nats::jetstream::kv::Keys> {
+    Ok(store.keys().await?)
+}
+
+/// `Store::keys` cannot filter, so keys not matching `filter` are skipped
+/// here.
+pub(crate) async fn keys_loop(
+    keys: async_nats::jetstream::kv::Keys,
+    filter: Option<String>,
+    task_receiver: TaskQueueReceiver<(), JetStreamListResult>,
+) {
+    let keys = keys.filter(move |key| match (key, filter.as_deref()) {
+        (Ok(key), Some(filter)) => key_matches(filter, key),
+        _ => true,
+    });
+    list_loop(Box::pin(keys), task_receiver).await
+}
+
+fn key_matches(filter: &str, key: &str) -> bool {
+    let mut key_tokens = key.split('.');
+    for filter_token in filter.split('.') {
+        match (filter_token, key_tokens.next()) {
+            (">", Some(_)) => return true,
+            (_, None) => return false,
+            ("*", Some(_)) => {},
+            (filter_token, Some(key_token)) if filter_token == key_token => {},
+            _ => return false,
+        }
+    }
+    key_tokens.next().is_none()
+}
+
+pub type WatchResult = Option<async_nats::jetstream::kv::Entry>;
+
+pub async fn watch_loop(
+    mut watch: async_nats::jetstream::kv::Watch,
+    mut task_receiver: TaskQueueReceiver<Option<Duration>, WatchResult>,
+) {
+    run_task_loop!(task_receiver, {
+        timeout => run_watch_next(&mut watch, timeout).await
+    });
+}
+
+async fn run_watch_next(
+    watch: &mut async_nats::jetstream::kv::Watch,
+    timeout: Option<Duration>,
+) -> anyhow::Result<WatchResult> {
+    let entry = match timeout {
+        Some(timeout) => tokio_timeout(timeout, watch.next()).await?,
+        None => watch.next().await,
+    };
+    Ok(entry.transpose()?)
+}
+
+/// Local copy of a bucket, kept by `cache_loop`.
+#[derive(Default)]
+pub(crate) struct KeyValueCacheState {
+    pub(crate) entries: HashMap<String, async_nats::jetstream::kv::Entry>,
+    pub(crate) revision: u64,
+    pub(crate) error: Option<String>,
+}
+
+impl KeyValueCacheState {
+    fn apply(&mut self, entry: async_nats::jetstream::kv::Entry) {
+        self.revision = self.revision.max(entry.revision);
+        match entry.operation {
+            async_nats::jetstream::kv::Operation::Put => {
+                self.entries.insert(entry.key.clone(), entry);
+            },
+            _ => {
+                self.entries.remove(&entry.key);
+            }
+        }
+    }
+}
+
+pub(crate) type KeyValueCache = Arc<RwLock<KeyValueCacheState>>;
+
+/// Reads the latest entry of every key, then returns the watch for the
+/// changes that follow. The initial entries end with the one whose delta
+/// is 0. An empty bucket sends none, so it is watched from its last
+/// sequence instead. If the remaining entries are removed before they are
+/// delivered, the last one never arrives either, so loading also ends once
+/// nothing arrived for `idle_timeout`; later entries still come from the watch.
+pub(crate) async fn kv_cache_load(
+    store: async_nats::jetstream::kv::Store,
+    idle_timeout: Duration,
+) -> anyhow::Result<(async_nats::jetstream::kv::Watch, KeyValueCache)> {
+    let mut state = KeyValueCacheState::default();
+
+    let status = store.status().await?;
+    if status.values() == 0 {
+        state.revision = status.info.state.last_sequence;
+        let watch = store.watch_all_from_revision(state.revision + 1).await?;
+        return Ok((watch, Arc::new(RwLock::new(state))));
+    }
+
+    let mut watch = store.watch_with_history(">").await?;
+    while let Ok(Some(entry)) = tokio_timeout(idle_timeout, watch.next()).await {
+        let entry = entry?;
+        let last = entry.delta == 0;
+        state.apply(entry);
+        if last {
+            break;
+        }
+    }
+    Ok((watch, Arc::new(RwLock::new(state))))
+}
+
+pub(crate) async fn cache_loop(
+    mut watch: async_nats::jetstream::kv::Watch,
+    cache: KeyValueCache,
+) {
+    let error = loop {
+        match watch.next().await {
+            Some(Ok(entry)) => cache
+                .write()
+                .unwrap_or_else(PoisonError::into_inner)
+                .apply(entry),
+            Some(Err(err)) => break err.to_string(),
+            None => break "watcher ended".to_string(),
+        }
+    };
+
+    cache.write().unwrap_or_else(PoisonError::into_inner).error = Some(error);
+}
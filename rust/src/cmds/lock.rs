@@ -0,0 +1,223 @@
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout as tokio_timeout;
+use tokio_stream::StreamExt;
+use crate::task::TaskQueueReceiver;
+
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Tries to create the key holding `token` until `timeout` is over, waits
+/// forever without timeout. Returns the revision of the key if acquired.
+pub(crate) async fn lock_acquire(
+    store: async_nats::jetstream::kv::Store,
+    key: String,
+    token: bytes::Bytes,
+    ttl: Duration,
+    timeout: Option<Duration>,
+) -> anyhow::Result<Option<u64>> {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+    loop {
+        match store.create_with_ttl(key.as_str(), token.clone(), ttl).await {
+            Ok(revision) => return Ok(Some(revision)),
+            Err(err) if err.kind() == async_nats::jetstream::kv::CreateErrorKind::AlreadyExists => {},
+            Err(err) => return Err(err.into()),
+        }
+
+        let mut sleep = LOCK_RETRY_INTERVAL;
+        if let Some(deadline) = deadline {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            sleep = sleep.min(remaining);
+        }
+        tokio::time::sleep(sleep).await;
+    }
+}
+
+/// Writes `token` again with a fresh TTL, if the key is still at
+/// `revision`. `Store::update` cannot set a TTL, so the message is
+/// published here, to the subject `Store` would use. Returns None if the
+/// lock was lost.
+pub(crate) async fn lock_renew(
+    store: async_nats::jetstream::kv::Store,
+    context: async_nats::jetstream::Context,
+    key: String,
+    token: bytes::Bytes,
+    revision: u64,
+    ttl: Duration,
+) -> anyhow::Result<Option<u64>> {
+    if !is_valid_key(&key) {
+        return Err(async_nats::jetstream::kv::UpdateError::new(
+            async_nats::jetstream::kv::UpdateErrorKind::InvalidKey
+        ).into());
+    }
+    let subject = format!("{}{}", store.put_prefix.as_ref().unwrap_or(&store.prefix), key);
+
+    if !store.use_jetstream_prefix {
+        let publish = async_nats::jetstream::message::PublishMessage::build()
+            .payload(token)
+            .expected_last_subject_sequence(revision)
+            .ttl(ttl);
+
+        return match context.send_publish(subject, publish).await?.await {
+            Ok(ack) => Ok(Some(ack.sequence)),
+            Err(err)
+                if err.kind() == async_nats::jetstream::context::PublishErrorKind::WrongLastSequence => {
+                Ok(None)
+            },
+            Err(err) => Err(err.into()),
+        };
+    }
+
+    // Only `send_request` puts the API prefix of the context in front of
+    // the subject, the ack is read from the inbox here.
+    let mut headers = async_nats::HeaderMap::new();
+    headers.insert(async_nats::header::NATS_EXPECTED_LAST_SUBJECT_SEQUENCE, revision.to_string());
+    headers.insert(async_nats::header::NATS_MESSAGE_TTL, ttl.as_secs().to_string());
+
+    let client = context.client();
+    let inbox = client.new_inbox();
+    let mut replies = client.subscribe(inbox.clone()).await?;
+    let request = async_nats::Request::new()
+        .headers(headers)
+        .payload(token)
+        .inbox(inbox);
+    context.send_request(subject, request).await?;
+
+    let reply = tokio_timeout(ttl, replies.next())
+        .await?
+        .ok_or_else(|| anyhow::anyhow!("No ack for lock renewal"))?;
+
+    let response: async_nats::jetstream::response::Response<async_nats::jetstream::publish::PublishAck> =
+        serde_json::from_slice(&reply.payload)?;
+    match response {
+        async_nats::jetstream::response::Response::Ok(ack) => Ok(Some(ack.sequence)),
+        async_nats::jetstream::response::Response::Err { error }
+            if error.error_code() == async_nats::jetstream::ErrorCode::STREAM_WRONG_LAST_SEQUENCE => {
+            Ok(None)
+        },
+        async_nats::jetstream::response::Response::Err { error } => Err(error.into()),
+    }
+}
+
+/// Same rule as `Store` applies to keys.
+fn is_valid_key(key: &str) -> bool {
+    !key.is_empty()
+        && !key.starts_with('.')
+        && !key.ends_with('.')
+        && key.chars().all(|c| c.is_ascii_alphanumeric() || "-/_=.".contains(c))
+}
+
+/// Deletes the key, if it is still at `revision`. Returns false if the
+/// lock was lost before.
+pub(crate) async fn lock_release(
+    store: async_nats::jetstream::kv::Store,
+    key: String,
+    revision: u64,
+) -> anyhow::Result<bool> {
+    match store.delete_expect_revision(key, Some(revision)).await {
+        Ok(()) => Ok(true),
+        Err(err)
+            if err.kind() == async_nats::jetstream::kv::DeleteErrorKind::WrongLastRevision => {
+            Ok(false)
+        },
+        Err(err) => Err(err.into()),
+    }
+}
+
+/// State of a candidate, shared with Python.
+#[derive(Default)]
+pub(crate) struct LeaderState {
+    /// Revision of the key while this candidate is the leader.
+    pub(crate) revision: Option<u64>,
+    pub(crate) error: Option<String>,
+}
+
+pub(crate) type Leader = Arc<Mutex<LeaderState>>;
+
+pub(crate) struct Candidate {
+    pub(crate) store: async_nats::jetstream::kv::Store,
+    pub(crate) context: async_nats::jetstream::Context,
+    pub(crate) key: String,
+    pub(crate) token: bytes::Bytes,
+    pub(crate) ttl: Duration,
+}
+
+/// Tries to become the leader, then renews the key every third of the
+/// TTL. A failed renewal is retried until the key may have expired, only
+/// then leadership is given up. Every change is sent to `changes`. Ends
+/// between two attempts once `stop` is notified.
+pub(crate) async fn election_loop(
+    candidate: Candidate,
+    leader: Leader,
+    changes: mpsc::UnboundedSender<bool>,
+    stop: Arc<tokio::sync::Notify>,
+) {
+    let Candidate { store, context, key, token, ttl } = candidate;
+    let mut renewed = Instant::now();
+
+    loop {
+        let revision = leader.lock().unwrap_or_else(PoisonError::into_inner).revision;
+        let started = Instant::now();
+
+        let result = match revision {
+            Some(revision) => lock_renew(
+                store.clone(), context.clone(), key.clone(), token.clone(), revision, ttl
+            ).await,
+            None => lock_acquire(
+                store.clone(), key.clone(), token.clone(), ttl, Some(Duration::ZERO)
+            ).await,
+        };
+
+        let (new_revision, sleep) = match result {
+            Ok(new_revision) => {
+                renewed = started;
+                leader.lock().unwrap_or_else(PoisonError::into_inner).error = None;
+                (new_revision, ttl / 3)
+            },
+            Err(err) => {
+                leader.lock().unwrap_or_else(PoisonError::into_inner).error = Some(err.to_string());
+                // The key expires `ttl` after the last successful renewal.
+                let new_revision = revision.filter(|_| renewed.elapsed() < ttl);
+                (new_revision, ttl / 10)
+            }
+        };
+
+        leader.lock().unwrap_or_else(PoisonError::into_inner).revision = new_revision;
+        if revision.is_some() != new_revision.is_some() {
+            let _ = changes.send(new_revision.is_some());
+        }
+
+        if tokio_timeout(sleep, stop.notified()).await.is_ok() {
+            return;
+        }
+    }
+}
+
+pub(crate) type LeaderChange = Option<bool>;
+
+pub(crate) async fn changes_loop(
+    mut changes: mpsc::UnboundedReceiver<bool>,
+    mut task_receiver: TaskQueueReceiver<Option<Duration>, LeaderChange>,
+) {
+    run_task_loop!(task_receiver, {
+        timeout => run_next_change(&mut changes, timeout).await
+    });
+}
+
+async fn run_next_change(
+    changes: &mut mpsc::UnboundedReceiver<bool>,
+    timeout: Option<Duration>,
+) -> anyhow::Result<LeaderChange> {
+    let change = match timeout {
+        Some(timeout) => tokio_timeout(timeout, changes.recv()).await?,
+        None => changes.recv().await,
+    };
+    Ok(change)
+}
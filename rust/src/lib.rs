@@ -9,13 +9,15 @@ mod cmds {
     pub(crate) mod client;
     pub(crate) mod subscriber;
     pub(crate) mod jetstream;
-
+    pub(crate) mod kv;
+    pub(crate) mod lock;
 }
 
 mod bindings {
     pub(crate) mod client;
     pub(crate) mod subscriber;
     pub(crate) mod jetstream;
+    pub(crate) mod exceptions;
     pub(crate) mod converters {
         pub(crate) mod connect_options;
         pub(crate) mod offset_datetime;
@@ -24,6 +26,7 @@ mod bindings {
         pub(crate) mod jetstream {
             pub(crate) mod stream;
             pub(crate) mod consumer;
+            pub(crate) mod kv;
         }
     }
 }
@@ -71,6 +74,9 @@ fn connect(
 #[pymodule]
 fn no_asyncio_nats(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<crate::bindings::client::Client>()?;
+    m.add_class::<crate::bindings::jetstream::lock::Lock>()?;
+    m.add_class::<crate::bindings::jetstream::lock::LeaderElection>()?;
     m.add_function(wrap_pyfunction!(connect, m)?)?;
+    crate::bindings::exceptions::register(m)?;
     Ok(())
 }
@@ -53,6 +53,13 @@ where
         (caller, receiver)
     }
 
+    pub fn make_clone(&self, py: pyo3::Python) -> Self {
+        TaskCaller {
+            task_queue: self.task_queue.clone(),
+            read_eventfd: self.read_eventfd.clone_ref(py),
+        }
+    }
+
     pub fn from_other<OC, OR>(py: pyo3::Python, other: &TaskCaller<OC, OR>) -> (Self, TaskQueueReceiver<C, R>) {
         Self::new(other.read_eventfd.clone_ref(py))
     }
@@ -1,6 +1,15 @@
 import os
 import fcntl
 from .no_asyncio_nats import connect as _connect_impl
+from .no_asyncio_nats import MissingHeartbeatError
+from .no_asyncio_nats import ConsumerDeletedError
+from .no_asyncio_nats import StreamAlreadyExistsError
+from .no_asyncio_nats import StreamNotFoundError
+from .no_asyncio_nats import StreamConfigError
+from .no_asyncio_nats import WrongLastSequenceError
+from .no_asyncio_nats import KeyExistsError
+from .no_asyncio_nats import Lock
+from .no_asyncio_nats import LeaderElection
 
 
 def connect(address, options=None):